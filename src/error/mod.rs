@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Error severity levels for classification
@@ -56,6 +57,49 @@ pub struct ErrorContext {
     pub metadata: HashMap<String, String>,
 }
 
+impl ErrorContext {
+    /// Start building a context for the given code/severity, with every
+    /// optional field unset
+    pub(crate) fn new(error_code: ErrorCode, severity: ErrorSeverity) -> Self {
+        Self {
+            error_code: error_code.to_string(),
+            severity,
+            timestamp: Utc::now(),
+            job_id: None,
+            user_id: None,
+            trace_id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Attach a job identifier
+    pub(crate) fn with_job_id(mut self, job_id: impl Into<String>) -> Self {
+        self.job_id = Some(job_id.into());
+        self
+    }
+
+    /// Attach a distributed-tracing trace ID at the call site
+    ///
+    /// `error_context()` never knows the caller's trace ID on its own;
+    /// chain this on afterward wherever the call site has one in scope.
+    pub fn with_trace_id(mut self, trace_id: impl Into<String>) -> Self {
+        self.trace_id = Some(trace_id.into());
+        self
+    }
+
+    /// Attach a user identifier at the call site
+    pub fn with_user_id(mut self, user_id: impl Into<String>) -> Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Attach an arbitrary metadata key/value pair
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+}
+
 /// Queue-specific errors
 #[derive(Debug, Clone)]
 pub enum QueueError {
@@ -77,8 +121,24 @@ pub enum QueueError {
     QueueFull(String),
     /// Invalid job payload
     InvalidPayload(String),
+    /// A dequeued job failed schema/version/range validation
+    ///
+    /// Distinct from [`QueueError::InvalidPayload`] (which covers payloads
+    /// that failed to deserialize at all) so a poison message that parses
+    /// fine but fails `JobPayload::validate` carries its `job_id` and is
+    /// routed straight to the Dead Letter Queue instead of blocking the
+    /// FIFO queue behind it or being retried forever.
+    InvalidJob { job_id: String, reason: String },
     /// Internal queue system error
     InternalError(String),
+    /// The worker task that drains this queue has terminated
+    ///
+    /// Carries the root cause captured once by a
+    /// [`crate::worker::WorkerCloseSignal`] and shared, via `Arc`, with
+    /// every caller that was waiting on the worker and every caller that
+    /// calls in afterward — so queue shutdown surfaces as a concrete,
+    /// debuggable error rather than a string of mysterious timeouts.
+    WorkerClosed(Arc<dyn StdError + Send + Sync>),
 }
 
 /// Worker-specific errors
@@ -108,6 +168,12 @@ pub enum WorkerError {
     },
     /// Internal worker error
     InternalError(String),
+    /// Job execution panicked
+    ///
+    /// Distinct from `InternalError` so panics (caught via
+    /// [`crate::worker::run_job_catching_unwind`]) can be told apart from
+    /// ordinary logic errors in logs and metrics.
+    Panicked { job_id: String, message: String },
 }
 
 /// User-friendly error response
@@ -125,10 +191,119 @@ pub struct ErrorResponse {
     pub request_id: Option<String>,
 }
 
+/// Machine-readable, centralized registry of every queue/worker error code
+///
+/// Replaces ad hoc `String` codes built inline in each `error_code()` impl so
+/// callers can route on the code itself (`is_retryable()`, `http_status()`)
+/// instead of reparsing a string. `Display` and `Serialize` both emit the
+/// same `SCREAMING_SNAKE_CASE` form the string codes used before, so this is
+/// a drop-in replacement at every serialization boundary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    QueueConnectionFailed,
+    QueueNetworkError,
+    QueueTimeoutError,
+    QueueEnqueueFailed,
+    QueueDequeueFailed,
+    QueueAckFailed,
+    QueueNackFailed,
+    QueueQueueFull,
+    QueueInvalidPayload,
+    QueueInvalidJob,
+    QueueInternalError,
+    QueueWorkerClosed,
+    WorkerJobProcessingFailed,
+    WorkerJobTimeout,
+    WorkerRetryableError,
+    WorkerMaxRetriesExceeded,
+    WorkerInvalidJobData,
+    WorkerInternalError,
+    WorkerPanicked,
+}
+
+impl ErrorCode {
+    /// Whether a caller should reasonably retry after seeing this code
+    ///
+    /// `false` for codes that represent a bad request/payload or a
+    /// permanent failure where retrying would just reproduce the same
+    /// outcome (invalid data, exhausted retries, panics).
+    pub fn is_retryable(&self) -> bool {
+        !matches!(
+            self,
+            ErrorCode::QueueInvalidPayload
+                | ErrorCode::QueueInvalidJob
+                | ErrorCode::QueueInternalError
+                | ErrorCode::WorkerMaxRetriesExceeded
+                | ErrorCode::WorkerInvalidJobData
+                | ErrorCode::WorkerInternalError
+                | ErrorCode::WorkerPanicked
+        )
+    }
+
+    /// The HTTP status an API layer should map this code to
+    pub fn http_status(&self) -> u16 {
+        match self {
+            ErrorCode::QueueConnectionFailed
+            | ErrorCode::QueueNetworkError
+            | ErrorCode::QueueEnqueueFailed
+            | ErrorCode::QueueDequeueFailed
+            | ErrorCode::QueueQueueFull
+            | ErrorCode::QueueWorkerClosed
+            | ErrorCode::WorkerRetryableError => 503,
+            ErrorCode::QueueTimeoutError | ErrorCode::WorkerJobTimeout => 504,
+            ErrorCode::QueueInvalidPayload | ErrorCode::QueueInvalidJob => 400,
+            ErrorCode::WorkerInvalidJobData => 422,
+            ErrorCode::QueueAckFailed
+            | ErrorCode::QueueNackFailed
+            | ErrorCode::QueueInternalError
+            | ErrorCode::WorkerJobProcessingFailed
+            | ErrorCode::WorkerMaxRetriesExceeded
+            | ErrorCode::WorkerInternalError
+            | ErrorCode::WorkerPanicked => 500,
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let code = match self {
+            ErrorCode::QueueConnectionFailed => "QUEUE_CONNECTION_FAILED",
+            ErrorCode::QueueNetworkError => "QUEUE_NETWORK_ERROR",
+            ErrorCode::QueueTimeoutError => "QUEUE_TIMEOUT_ERROR",
+            ErrorCode::QueueEnqueueFailed => "QUEUE_ENQUEUE_FAILED",
+            ErrorCode::QueueDequeueFailed => "QUEUE_DEQUEUE_FAILED",
+            ErrorCode::QueueAckFailed => "QUEUE_ACK_FAILED",
+            ErrorCode::QueueNackFailed => "QUEUE_NACK_FAILED",
+            ErrorCode::QueueQueueFull => "QUEUE_QUEUE_FULL",
+            ErrorCode::QueueInvalidPayload => "QUEUE_INVALID_PAYLOAD",
+            ErrorCode::QueueInvalidJob => "QUEUE_INVALID_JOB",
+            ErrorCode::QueueInternalError => "QUEUE_INTERNAL_ERROR",
+            ErrorCode::QueueWorkerClosed => "QUEUE_WORKER_CLOSED",
+            ErrorCode::WorkerJobProcessingFailed => "WORKER_JOB_PROCESSING_FAILED",
+            ErrorCode::WorkerJobTimeout => "WORKER_JOB_TIMEOUT",
+            ErrorCode::WorkerRetryableError => "WORKER_RETRYABLE_ERROR",
+            ErrorCode::WorkerMaxRetriesExceeded => "WORKER_MAX_RETRIES_EXCEEDED",
+            ErrorCode::WorkerInvalidJobData => "WORKER_INVALID_JOB_DATA",
+            ErrorCode::WorkerInternalError => "WORKER_INTERNAL_ERROR",
+            ErrorCode::WorkerPanicked => "WORKER_PANICKED",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
 // Trait implementations for error codes, user messages, and log context
 pub trait ErrorExt {
     /// Get machine-readable error code
-    fn error_code(&self) -> String;
+    fn error_code(&self) -> ErrorCode;
 
     /// Get user-friendly error message (hides internal details)
     fn user_message(&self) -> String;
@@ -138,21 +313,36 @@ pub trait ErrorExt {
 
     /// Get error severity level
     fn severity(&self) -> ErrorSeverity;
+
+    /// Build a structured, machine-parseable error context
+    ///
+    /// Populates `job_id` and `metadata` from the variant's own fields;
+    /// `trace_id`/`user_id` are left unset here since only the call site
+    /// knows them — attach those with [`ErrorContext::with_trace_id`] /
+    /// [`ErrorContext::with_user_id`] before logging.
+    fn error_context(&self) -> ErrorContext;
+
+    /// Serialize `error_context()` to JSON for log ingestion pipelines
+    fn log_context_json(&self) -> String {
+        serde_json::to_string(&self.error_context()).unwrap_or_else(|_| self.log_context())
+    }
 }
 
 impl ErrorExt for QueueError {
-    fn error_code(&self) -> String {
+    fn error_code(&self) -> ErrorCode {
         match self {
-            QueueError::ConnectionFailed(_) => "QUEUE_CONNECTION_FAILED".to_string(),
-            QueueError::NetworkError(_) => "QUEUE_NETWORK_ERROR".to_string(),
-            QueueError::TimeoutError(_) => "QUEUE_TIMEOUT_ERROR".to_string(),
-            QueueError::EnqueueFailed { .. } => "QUEUE_ENQUEUE_FAILED".to_string(),
-            QueueError::DequeueFailed(_) => "QUEUE_DEQUEUE_FAILED".to_string(),
-            QueueError::AckFailed { .. } => "QUEUE_ACK_FAILED".to_string(),
-            QueueError::NackFailed { .. } => "QUEUE_NACK_FAILED".to_string(),
-            QueueError::QueueFull(_) => "QUEUE_QUEUE_FULL".to_string(),
-            QueueError::InvalidPayload(_) => "QUEUE_INVALID_PAYLOAD".to_string(),
-            QueueError::InternalError(_) => "QUEUE_INTERNAL_ERROR".to_string(),
+            QueueError::ConnectionFailed(_) => ErrorCode::QueueConnectionFailed,
+            QueueError::NetworkError(_) => ErrorCode::QueueNetworkError,
+            QueueError::TimeoutError(_) => ErrorCode::QueueTimeoutError,
+            QueueError::EnqueueFailed { .. } => ErrorCode::QueueEnqueueFailed,
+            QueueError::DequeueFailed(_) => ErrorCode::QueueDequeueFailed,
+            QueueError::AckFailed { .. } => ErrorCode::QueueAckFailed,
+            QueueError::NackFailed { .. } => ErrorCode::QueueNackFailed,
+            QueueError::QueueFull(_) => ErrorCode::QueueQueueFull,
+            QueueError::InvalidPayload(_) => ErrorCode::QueueInvalidPayload,
+            QueueError::InvalidJob { .. } => ErrorCode::QueueInvalidJob,
+            QueueError::InternalError(_) => ErrorCode::QueueInternalError,
+            QueueError::WorkerClosed(_) => ErrorCode::QueueWorkerClosed,
         }
     }
 
@@ -172,23 +362,30 @@ impl ErrorExt for QueueError {
                 "Job processing encountered an issue. Please contact support if this persists."
                     .to_string()
             }
-            QueueError::InvalidPayload(_) => {
+            QueueError::InvalidPayload(_) | QueueError::InvalidJob { .. } => {
                 "Invalid request format. Please check your input and try again.".to_string()
             }
             QueueError::InternalError(_) => {
                 "An unexpected error occurred. Please try again or contact support.".to_string()
             }
+            QueueError::WorkerClosed(_) => {
+                "Service temporarily unavailable. Please try again in a few moments.".to_string()
+            }
         }
     }
 
     fn log_context(&self) -> String {
-        let timestamp = Utc::now().to_rfc3339();
-        let error_code = self.error_code();
-        let severity = self.severity();
+        // Thin wrapper over the structured form: the header comes straight
+        // from `error_context()`, only the per-variant detail fields below
+        // are still hand-formatted to preserve the existing flat-string shape.
+        let structured = self.error_context();
 
         let mut context = format!(
             "[{}] error_code={} severity={} timestamp={}",
-            error_code, error_code, severity, timestamp
+            structured.error_code,
+            structured.error_code,
+            structured.severity,
+            structured.timestamp.to_rfc3339()
         );
 
         // Add specific context based on error type
@@ -223,9 +420,15 @@ impl ErrorExt for QueueError {
             QueueError::InvalidPayload(reason) => {
                 context.push_str(&format!(" reason=\"{}\"", reason));
             }
+            QueueError::InvalidJob { job_id, reason } => {
+                context.push_str(&format!(" job_id=\"{}\" reason=\"{}\"", job_id, reason));
+            }
             QueueError::InternalError(reason) => {
                 context.push_str(&format!(" reason=\"{}\"", reason));
             }
+            QueueError::WorkerClosed(root_cause) => {
+                context.push_str(&format!(" root_cause=\"{}\"", root_cause));
+            }
         }
 
         context
@@ -243,19 +446,47 @@ impl ErrorExt for QueueError {
             | QueueError::NackFailed { .. }
             | QueueError::QueueFull(_) => ErrorSeverity::Warning,
             QueueError::InvalidPayload(_) => ErrorSeverity::Warning,
+            QueueError::InvalidJob { .. } => ErrorSeverity::Error,
+            QueueError::WorkerClosed(_) => ErrorSeverity::Critical,
+        }
+    }
+
+    fn error_context(&self) -> ErrorContext {
+        let base = ErrorContext::new(self.error_code(), self.severity());
+
+        match self {
+            QueueError::ConnectionFailed(reason)
+            | QueueError::NetworkError(reason)
+            | QueueError::TimeoutError(reason)
+            | QueueError::DequeueFailed(reason)
+            | QueueError::QueueFull(reason)
+            | QueueError::InvalidPayload(reason)
+            | QueueError::InternalError(reason) => base.with_metadata("reason", reason),
+            QueueError::EnqueueFailed { payload_id, reason } => base
+                .with_metadata("payload_id", payload_id)
+                .with_metadata("reason", reason),
+            QueueError::AckFailed { job_id, reason }
+            | QueueError::NackFailed { job_id, reason }
+            | QueueError::InvalidJob { job_id, reason } => {
+                base.with_job_id(job_id).with_metadata("reason", reason)
+            }
+            QueueError::WorkerClosed(root_cause) => {
+                base.with_metadata("root_cause", root_cause.to_string())
+            }
         }
     }
 }
 
 impl ErrorExt for WorkerError {
-    fn error_code(&self) -> String {
+    fn error_code(&self) -> ErrorCode {
         match self {
-            WorkerError::JobProcessingFailed { .. } => "WORKER_JOB_PROCESSING_FAILED".to_string(),
-            WorkerError::JobTimeout { .. } => "WORKER_JOB_TIMEOUT".to_string(),
-            WorkerError::RetryableError { .. } => "WORKER_RETRYABLE_ERROR".to_string(),
-            WorkerError::MaxRetriesExceeded { .. } => "WORKER_MAX_RETRIES_EXCEEDED".to_string(),
-            WorkerError::InvalidJobData { .. } => "WORKER_INVALID_JOB_DATA".to_string(),
-            WorkerError::InternalError(_) => "WORKER_INTERNAL_ERROR".to_string(),
+            WorkerError::JobProcessingFailed { .. } => ErrorCode::WorkerJobProcessingFailed,
+            WorkerError::JobTimeout { .. } => ErrorCode::WorkerJobTimeout,
+            WorkerError::RetryableError { .. } => ErrorCode::WorkerRetryableError,
+            WorkerError::MaxRetriesExceeded { .. } => ErrorCode::WorkerMaxRetriesExceeded,
+            WorkerError::InvalidJobData { .. } => ErrorCode::WorkerInvalidJobData,
+            WorkerError::InternalError(_) => ErrorCode::WorkerInternalError,
+            WorkerError::Panicked { .. } => ErrorCode::WorkerPanicked,
         }
     }
 
@@ -285,17 +516,24 @@ impl ErrorExt for WorkerError {
             WorkerError::InternalError(_) => {
                 "An unexpected error occurred during processing. Please try again.".to_string()
             }
+            WorkerError::Panicked { .. } => {
+                "An unexpected error occurred during processing. Please try again.".to_string()
+            }
         }
     }
 
     fn log_context(&self) -> String {
-        let timestamp = Utc::now().to_rfc3339();
-        let error_code = self.error_code();
-        let severity = self.severity();
+        // Thin wrapper over the structured form: the header comes straight
+        // from `error_context()`, only the per-variant detail fields below
+        // are still hand-formatted to preserve the existing flat-string shape.
+        let structured = self.error_context();
 
         let mut context = format!(
             "[{}] error_code={} severity={} timestamp={}",
-            error_code, error_code, severity, timestamp
+            structured.error_code,
+            structured.error_code,
+            structured.severity,
+            structured.timestamp.to_rfc3339()
         );
 
         // Add specific context based on error type
@@ -353,6 +591,9 @@ impl ErrorExt for WorkerError {
             WorkerError::InternalError(reason) => {
                 context.push_str(&format!(" reason=\"{}\"", reason));
             }
+            WorkerError::Panicked { job_id, message } => {
+                context.push_str(&format!(" job_id=\"{}\" panic_message=\"{}\"", job_id, message));
+            }
         }
 
         context
@@ -369,6 +610,87 @@ impl ErrorExt for WorkerError {
             WorkerError::MaxRetriesExceeded { .. } | WorkerError::InternalError(_) => {
                 ErrorSeverity::Error
             }
+            WorkerError::Panicked { .. } => ErrorSeverity::Critical,
+        }
+    }
+
+    fn error_context(&self) -> ErrorContext {
+        let base = ErrorContext::new(self.error_code(), self.severity());
+
+        match self {
+            WorkerError::JobProcessingFailed {
+                job_id,
+                attempts,
+                reason,
+            } => base
+                .with_job_id(job_id)
+                .with_metadata("attempts", attempts.to_string())
+                .with_metadata("reason", reason),
+            WorkerError::JobTimeout { job_id, timeout } => base
+                .with_job_id(job_id)
+                .with_metadata("timeout_seconds", timeout.as_secs().to_string()),
+            WorkerError::RetryableError {
+                job_id,
+                attempts,
+                next_retry_in,
+                reason,
+            } => base
+                .with_job_id(job_id)
+                .with_metadata("attempts", attempts.to_string())
+                .with_metadata("next_retry_in_seconds", next_retry_in.as_secs().to_string())
+                .with_metadata("reason", reason),
+            WorkerError::MaxRetriesExceeded {
+                job_id,
+                total_attempts,
+            } => base
+                .with_job_id(job_id)
+                .with_metadata("total_attempts", total_attempts.to_string()),
+            WorkerError::InvalidJobData {
+                job_id,
+                validation_errors,
+            } => base
+                .with_job_id(job_id)
+                .with_metadata("validation_errors", validation_errors.join(", ")),
+            WorkerError::InternalError(reason) => base.with_metadata("reason", reason),
+            WorkerError::Panicked { job_id, message } => base
+                .with_job_id(job_id)
+                .with_metadata("panic_message", message),
+        }
+    }
+}
+
+impl WorkerError {
+    /// Build the appropriate error variant for a failed attempt, given the
+    /// policy's retry decision for this job
+    ///
+    /// Centralizes the `RetryableError` vs `MaxRetriesExceeded` choice so
+    /// callers don't have to duplicate the `attempts < max` comparison
+    /// alongside every call site that already has a
+    /// [`crate::worker::retry::MaxRetries`] policy in hand.
+    pub fn classify_and_retry(
+        job_id: String,
+        attempts: u32,
+        max_retries: crate::worker::retry::MaxRetries,
+        next_retry_in: Duration,
+        reason: String,
+    ) -> Self {
+        let can_retry = match max_retries {
+            crate::worker::retry::MaxRetries::Infinite => true,
+            crate::worker::retry::MaxRetries::Count(max) => attempts < max,
+        };
+
+        if can_retry {
+            WorkerError::RetryableError {
+                job_id,
+                attempts,
+                next_retry_in,
+                reason,
+            }
+        } else {
+            WorkerError::MaxRetriesExceeded {
+                job_id,
+                total_attempts: attempts,
+            }
         }
     }
 }
@@ -392,10 +714,28 @@ impl StdError for QueueError {}
 impl StdError for WorkerError {}
 
 /// Classify a generic error into our error types
-pub fn classify_error(error: &(dyn StdError + Send + Sync)) -> QueueError {
-    let error_msg = error.to_string();
+///
+/// Walks the `source()` chain looking for a concrete error type we know how
+/// to map precisely — `std::io::Error` by `ErrorKind`, any `serde_json::Error`
+/// to `InvalidPayload` — and only falls back to substring heuristics on the
+/// top-level message when nothing in the chain downcasts to a known type.
+/// This is more robust than message-sniffing alone since it survives
+/// wrapping (e.g. a Redis error wrapping a `std::io::Error`) and doesn't
+/// depend on the wording of any particular error's `Display` impl.
+pub fn classify_error(error: &(dyn StdError + Send + Sync + 'static)) -> QueueError {
+    let mut source: Option<&(dyn StdError + 'static)> = Some(error);
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            return classify_io_error(io_err);
+        }
+        if let Some(json_err) = err.downcast_ref::<serde_json::Error>() {
+            return QueueError::InvalidPayload(json_err.to_string());
+        }
+        source = err.source();
+    }
 
-    // Simple classification based on error message content
+    // No typed match anywhere in the chain; fall back to message sniffing
+    let error_msg = error.to_string();
     if error_msg.contains("connection") || error_msg.contains("connect") {
         QueueError::ConnectionFailed(error_msg)
     } else if error_msg.contains("timeout") || error_msg.contains("timed out") {
@@ -409,10 +749,36 @@ pub fn classify_error(error: &(dyn StdError + Send + Sync)) -> QueueError {
     }
 }
 
+/// Map a `std::io::Error` to a `QueueError` by its `ErrorKind`
+fn classify_io_error(io_err: &std::io::Error) -> QueueError {
+    use std::io::ErrorKind;
+
+    match io_err.kind() {
+        ErrorKind::TimedOut => QueueError::TimeoutError(io_err.to_string()),
+        ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionReset
+        | ErrorKind::ConnectionAborted
+        | ErrorKind::NotConnected => QueueError::ConnectionFailed(io_err.to_string()),
+        _ => QueueError::InternalError(io_err.to_string()),
+    }
+}
+
+impl From<serde_json::Error> for QueueError {
+    fn from(error: serde_json::Error) -> Self {
+        QueueError::InvalidPayload(error.to_string())
+    }
+}
+
+impl From<std::io::Error> for QueueError {
+    fn from(error: std::io::Error) -> Self {
+        classify_io_error(&error)
+    }
+}
+
 /// Create a user-friendly error response from any error that implements ErrorExt
 pub fn create_error_response<E: ErrorExt>(error: &E) -> ErrorResponse {
     ErrorResponse {
-        error_code: error.error_code(),
+        error_code: error.error_code().to_string(),
         user_message: error.user_message(),
         severity: error.severity().to_string(),
         timestamp: Utc::now().to_rfc3339(),
@@ -435,7 +801,7 @@ mod tests {
     #[test]
     fn test_queue_error_basic() {
         let error = QueueError::ConnectionFailed("Test connection failed".to_string());
-        assert_eq!(error.error_code(), "QUEUE_CONNECTION_FAILED");
+        assert_eq!(error.error_code().to_string(), "QUEUE_CONNECTION_FAILED");
         assert!(error.user_message().contains("temporarily unavailable"));
         assert_eq!(error.severity(), ErrorSeverity::Error);
     }
@@ -447,8 +813,161 @@ mod tests {
             attempts: 2,
             reason: "Test failure".to_string(),
         };
-        assert_eq!(error.error_code(), "WORKER_JOB_PROCESSING_FAILED");
+        assert_eq!(error.error_code().to_string(), "WORKER_JOB_PROCESSING_FAILED");
         assert!(error.user_message().contains("taking longer"));
         assert_eq!(error.severity(), ErrorSeverity::Warning);
     }
+
+    #[test]
+    fn test_classify_error_io_timeout() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::TimedOut, "operation timed out");
+        let classified = classify_error(&io_err);
+        assert!(matches!(classified, QueueError::TimeoutError(_)));
+    }
+
+    #[test]
+    fn test_classify_error_io_connection_refused() {
+        let io_err =
+            std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused by peer");
+        let classified = classify_error(&io_err);
+        assert!(matches!(classified, QueueError::ConnectionFailed(_)));
+    }
+
+    #[test]
+    fn test_classify_error_serde_json() {
+        let json_err = serde_json::from_str::<serde_json::Value>("{not valid json").unwrap_err();
+        let classified = classify_error(&json_err);
+        assert!(matches!(classified, QueueError::InvalidPayload(_)));
+    }
+
+    #[test]
+    fn test_classify_error_falls_back_to_message_sniffing() {
+        #[derive(Debug)]
+        struct OpaqueError;
+        impl fmt::Display for OpaqueError {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "connection lost to upstream")
+            }
+        }
+        impl StdError for OpaqueError {}
+
+        let classified = classify_error(&OpaqueError);
+        assert!(matches!(classified, QueueError::ConnectionFailed(_)));
+    }
+
+    #[test]
+    fn test_worker_panicked_error() {
+        let error = WorkerError::Panicked {
+            job_id: "job-9".to_string(),
+            message: "index out of bounds".to_string(),
+        };
+
+        assert_eq!(error.error_code().to_string(), "WORKER_PANICKED");
+        assert_eq!(error.severity(), ErrorSeverity::Critical);
+        assert!(error.log_context().contains("index out of bounds"));
+    }
+
+    #[test]
+    fn test_worker_closed_error() {
+        let root_cause: Arc<dyn StdError + Send + Sync> =
+            Arc::new(std::io::Error::new(std::io::ErrorKind::Other, "worker panicked"));
+        let error = QueueError::WorkerClosed(root_cause);
+
+        assert_eq!(error.error_code().to_string(), "QUEUE_WORKER_CLOSED");
+        assert!(error.user_message().contains("temporarily unavailable"));
+        assert_eq!(error.severity(), ErrorSeverity::Critical);
+        assert!(error.log_context().contains("worker panicked"));
+    }
+
+    #[test]
+    fn test_classify_and_retry_under_cap() {
+        use crate::worker::retry::MaxRetries;
+
+        let error = WorkerError::classify_and_retry(
+            "job-1".to_string(),
+            1,
+            MaxRetries::Count(3),
+            Duration::from_millis(500),
+            "transient failure".to_string(),
+        );
+
+        match error {
+            WorkerError::RetryableError { attempts, .. } => assert_eq!(attempts, 1),
+            other => panic!("expected RetryableError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_and_retry_exhausted() {
+        use crate::worker::retry::MaxRetries;
+
+        let error = WorkerError::classify_and_retry(
+            "job-1".to_string(),
+            3,
+            MaxRetries::Count(3),
+            Duration::from_millis(500),
+            "transient failure".to_string(),
+        );
+
+        match error {
+            WorkerError::MaxRetriesExceeded { total_attempts, .. } => {
+                assert_eq!(total_attempts, 3)
+            }
+            other => panic!("expected MaxRetriesExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_context_populates_job_id_and_metadata() {
+        let error = WorkerError::JobProcessingFailed {
+            job_id: "job-42".to_string(),
+            attempts: 2,
+            reason: "Gemini API timeout".to_string(),
+        };
+
+        let context = error.error_context();
+        assert_eq!(context.job_id, Some("job-42".to_string()));
+        assert_eq!(context.metadata.get("attempts"), Some(&"2".to_string()));
+        assert_eq!(
+            context.metadata.get("reason"),
+            Some(&"Gemini API timeout".to_string())
+        );
+        assert!(context.trace_id.is_none());
+    }
+
+    #[test]
+    fn test_error_context_builder_attaches_trace_and_user_id() {
+        let error = QueueError::QueueFull("Maximum capacity reached".to_string());
+
+        let context = error
+            .error_context()
+            .with_trace_id("trace-abc")
+            .with_user_id("user-1");
+
+        assert_eq!(context.trace_id, Some("trace-abc".to_string()));
+        assert_eq!(context.user_id, Some("user-1".to_string()));
+    }
+
+    #[test]
+    fn test_log_context_json_round_trips_through_error_context() {
+        let error = QueueError::DequeueFailed("Queue locked".to_string());
+
+        let json = error.log_context_json();
+        let parsed: ErrorContext = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.error_code, "QUEUE_DEQUEUE_FAILED");
+        assert_eq!(parsed.metadata.get("reason"), Some(&"Queue locked".to_string()));
+    }
+
+    #[test]
+    fn test_log_context_still_matches_flat_string_shape() {
+        let error = QueueError::EnqueueFailed {
+            payload_id: "payload-789".to_string(),
+            reason: "Queue at maximum capacity".to_string(),
+        };
+
+        let log_context = error.log_context();
+        assert!(log_context.contains("error_code=QUEUE_ENQUEUE_FAILED"));
+        assert!(log_context.contains("payload_id=\"payload-789\""));
+    }
 }
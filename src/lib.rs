@@ -7,6 +7,7 @@ pub mod agents;
 pub mod api;
 pub mod auth;
 pub mod models;
+pub mod notifier;
 pub mod queue;
 pub mod utils;
 pub mod worker;
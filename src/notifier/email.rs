@@ -0,0 +1,92 @@
+//! Email/SMTP [`Notifier`] sink
+
+use crate::notifier::{DlqNotification, Notifier, NotifierError};
+use async_trait::async_trait;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+/// Sends a formatted failure report over SMTP to an operations address
+pub struct EmailNotifier {
+    smtp_host: String,
+    smtp_port: u16,
+    username: String,
+    password: String,
+    from_address: String,
+    to_address: String,
+}
+
+impl EmailNotifier {
+    /// Notify by emailing `to_address` from `from_address` via the SMTP
+    /// server at `smtp_host`:`smtp_port`, authenticating with
+    /// `username`/`password`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        smtp_host: impl Into<String>,
+        smtp_port: u16,
+        username: impl Into<String>,
+        password: impl Into<String>,
+        from_address: impl Into<String>,
+        to_address: impl Into<String>,
+    ) -> Self {
+        Self {
+            smtp_host: smtp_host.into(),
+            smtp_port,
+            username: username.into(),
+            password: password.into(),
+            from_address: from_address.into(),
+            to_address: to_address.into(),
+        }
+    }
+
+    /// Render `event` as a plain-text failure report
+    fn render_body(event: &DlqNotification) -> String {
+        format!(
+            "Job {job_id} permanently failed after {attempts} attempt(s).\n\n\
+             User: {user_id}\n\
+             Question: {question}\n\
+             Trace ID: {trace_id}\n\
+             Error: {error}\n",
+            job_id = event.job_id,
+            attempts = event.attempts,
+            user_id = event.user_id,
+            question = event.question,
+            trace_id = event.trace_id.as_deref().unwrap_or("-"),
+            error = event.error,
+        )
+    }
+}
+
+#[async_trait]
+impl Notifier for EmailNotifier {
+    async fn notify(&self, event: &DlqNotification) -> Result<(), NotifierError> {
+        let email = Message::builder()
+            .from(
+                self.from_address
+                    .parse()
+                    .map_err(|e: lettre::address::AddressError| {
+                        NotifierError::Smtp(e.to_string())
+                    })?,
+            )
+            .to(self
+                .to_address
+                .parse()
+                .map_err(|e: lettre::address::AddressError| NotifierError::Smtp(e.to_string()))?)
+            .subject(format!("Dead-lettered job {}", event.job_id))
+            .body(Self::render_body(event))
+            .map_err(|e| NotifierError::Smtp(e.to_string()))?;
+
+        let credentials = Credentials::new(self.username.clone(), self.password.clone());
+        let mailer = AsyncSmtpTransport::<Tokio1Executor>::relay(&self.smtp_host)
+            .map_err(|e| NotifierError::Smtp(e.to_string()))?
+            .port(self.smtp_port)
+            .credentials(credentials)
+            .build();
+
+        mailer
+            .send(email)
+            .await
+            .map_err(|e| NotifierError::Smtp(e.to_string()))?;
+
+        Ok(())
+    }
+}
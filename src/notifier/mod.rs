@@ -0,0 +1,234 @@
+//! Dead-letter notification subsystem
+//!
+//! A job that exhausts its retries and lands in the Dead Letter Queue
+//! otherwise vanishes silently from an operator's point of view. This module
+//! defines a [`Notifier`] trait for anything that can be alerted when that
+//! happens, a [`NotifierRegistry`] for fanning a single [`DlqNotification`]
+//! out to every registered sink, and two concrete sinks: [`webhook`] (an
+//! HTTP POST) and [`email`] (SMTP via `lettre`).
+//!
+//! [`crate::queue::notifying_queue::NotifyingQueue`] is what actually wires
+//! this into a [`crate::queue::Queue`]'s NACK-to-DLQ path.
+
+pub mod email;
+pub mod webhook;
+
+pub use email::EmailNotifier;
+pub use webhook::WebhookNotifier;
+
+use crate::queue::{DeadLetterEntry, QueuedJob};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Everything a sink needs to report a job that was permanently given up on
+#[derive(Debug, Clone, Serialize)]
+pub struct DlqNotification {
+    /// The job that was dead-lettered
+    pub job_id: String,
+    /// The user who requested it
+    pub user_id: Uuid,
+    /// The question it was answering
+    pub question: String,
+    /// Distributed-tracing trace ID, if the job carried one
+    pub trace_id: Option<String>,
+    /// The final error that caused it to be given up on
+    pub error: String,
+    /// How many delivery attempts were made before giving up
+    pub attempts: u32,
+}
+
+impl DlqNotification {
+    /// Build a notification from an existing [`DeadLetterEntry`]
+    pub fn from_entry(entry: &DeadLetterEntry) -> Self {
+        Self {
+            job_id: entry.job_id.clone(),
+            user_id: entry.payload.user_id,
+            question: entry.payload.question.clone(),
+            trace_id: entry.payload.trace_id.clone(),
+            error: entry.error.clone(),
+            attempts: entry.attempts,
+        }
+    }
+
+    /// Build a notification from a [`QueuedJob`] being moved to the DLQ,
+    /// paired with the reason it's being given up on
+    pub fn from_job(job: &QueuedJob, reason: String) -> Self {
+        Self {
+            job_id: job.job_id.clone(),
+            user_id: job.payload.user_id,
+            question: job.payload.question.clone(),
+            trace_id: job.payload.trace_id.clone(),
+            error: reason,
+            attempts: job.attempts,
+        }
+    }
+}
+
+/// A sink that can be alerted when a job is dead-lettered
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Deliver `event` to this sink
+    async fn notify(&self, event: &DlqNotification) -> Result<(), NotifierError>;
+}
+
+/// Error a [`Notifier`] failed to deliver with
+#[derive(Debug)]
+pub enum NotifierError {
+    /// The webhook HTTP request failed
+    Http(String),
+    /// The email failed to send over SMTP
+    Smtp(String),
+}
+
+impl std::fmt::Display for NotifierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            NotifierError::Http(msg) => write!(f, "webhook notifier error: {}", msg),
+            NotifierError::Smtp(msg) => write!(f, "email notifier error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for NotifierError {}
+
+/// Fans a [`DlqNotification`] out to every registered [`Notifier`]
+///
+/// Mirrors [`crate::worker::dispatch::Dispatcher`]'s builder-style
+/// `register` pattern. A failing notifier is logged and skipped — it never
+/// stops the remaining notifiers from running, and it never surfaces back
+/// to the queue operation that triggered it.
+#[derive(Clone, Default)]
+pub struct NotifierRegistry {
+    notifiers: Vec<Arc<dyn Notifier>>,
+}
+
+impl NotifierRegistry {
+    /// A registry with no notifiers registered
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `notifier`, so it receives every future [`DlqNotification`]
+    pub fn register(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifiers.push(notifier);
+        self
+    }
+
+    /// Deliver `event` to every registered notifier, logging (but not
+    /// propagating) any that fail
+    pub async fn notify_all(&self, event: &DlqNotification) {
+        for notifier in &self.notifiers {
+            if let Err(e) = notifier.notify(event).await {
+                eprintln!(
+                    "notifier failed to deliver DLQ notification for job {}: {}",
+                    event.job_id, e
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queue::JobPayload;
+    use chrono::Utc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn sample_entry() -> DeadLetterEntry {
+        DeadLetterEntry {
+            job_id: "job-1".to_string(),
+            payload: JobPayload {
+                job_id: "job-1".to_string(),
+                user_id: Uuid::new_v4(),
+                question: "Will this notify?".to_string(),
+                card_count: 3,
+                schema_version: "1".to_string(),
+                prompt_version: "v2025-11-20-a".to_string(),
+                dedupe_key: None,
+                trace_id: Some("trace-1".to_string()),
+                created_at: Utc::now(),
+                scheduled_at: None,
+                priority: 0,
+                metadata: serde_json::json!({}),
+            },
+            error: "max attempts exceeded".to_string(),
+            attempts: 5,
+            failed_at: Utc::now(),
+        }
+    }
+
+    struct CountingNotifier {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Notifier for CountingNotifier {
+        async fn notify(&self, _event: &DlqNotification) -> Result<(), NotifierError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    struct FailingNotifier;
+
+    #[async_trait]
+    impl Notifier for FailingNotifier {
+        async fn notify(&self, _event: &DlqNotification) -> Result<(), NotifierError> {
+            Err(NotifierError::Http("connection refused".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_dlq_notification_from_entry_copies_relevant_fields() {
+        let entry = sample_entry();
+        let event = DlqNotification::from_entry(&entry);
+
+        assert_eq!(event.job_id, "job-1");
+        assert_eq!(event.user_id, entry.payload.user_id);
+        assert_eq!(event.question, "Will this notify?");
+        assert_eq!(event.trace_id.as_deref(), Some("trace-1"));
+        assert_eq!(event.error, "max attempts exceeded");
+        assert_eq!(event.attempts, 5);
+    }
+
+    #[tokio::test]
+    async fn test_notify_all_fans_out_to_every_registered_notifier() {
+        let calls_a = Arc::new(AtomicUsize::new(0));
+        let calls_b = Arc::new(AtomicUsize::new(0));
+
+        let registry = NotifierRegistry::new()
+            .register(Arc::new(CountingNotifier {
+                calls: calls_a.clone(),
+            }))
+            .register(Arc::new(CountingNotifier {
+                calls: calls_b.clone(),
+            }));
+
+        registry
+            .notify_all(&DlqNotification::from_entry(&sample_entry()))
+            .await;
+
+        assert_eq!(calls_a.load(Ordering::SeqCst), 1);
+        assert_eq!(calls_b.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_notify_all_skips_past_a_failing_notifier() {
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let registry = NotifierRegistry::new()
+            .register(Arc::new(FailingNotifier))
+            .register(Arc::new(CountingNotifier {
+                calls: calls.clone(),
+            }));
+
+        registry
+            .notify_all(&DlqNotification::from_entry(&sample_entry()))
+            .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
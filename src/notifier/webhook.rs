@@ -0,0 +1,36 @@
+//! HTTP webhook [`Notifier`] sink
+
+use crate::notifier::{DlqNotification, Notifier, NotifierError};
+use async_trait::async_trait;
+
+/// POSTs a JSON-encoded [`DlqNotification`] to a configured URL
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    /// Notify by POSTing to `url`
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, event: &DlqNotification) -> Result<(), NotifierError> {
+        self.client
+            .post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .map_err(|e| NotifierError::Http(e.to_string()))?
+            .error_for_status()
+            .map_err(|e| NotifierError::Http(e.to_string()))?;
+
+        Ok(())
+    }
+}
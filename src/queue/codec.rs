@@ -0,0 +1,285 @@
+//! Pluggable wire-format codec for [`JobPayload`]
+//!
+//! `JobPayload` has historically been serialized as `serde_json` everywhere
+//! it crosses a queue boundary — readable, but verbose on the wire and
+//! slower to encode/decode at high enqueue throughput than a compact binary
+//! format. [`PayloadCodec`] abstracts the encode/decode step so a backend
+//! can plug in [`BincodeCodec`] for throughput while keeping [`JsonCodec`]
+//! (still the default, for backward compatibility with payloads already on
+//! the wire and for ad-hoc debugging/interop) available as a drop-in
+//! alternative.
+
+use crate::queue::types::SUPPORTED_SCHEMA_VERSION;
+use crate::queue::JobPayload;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::error::Error as StdError;
+use std::fmt;
+use uuid::Uuid;
+
+/// Error encoding or decoding a [`JobPayload`] through a [`PayloadCodec`]
+#[derive(Debug)]
+pub enum CodecError {
+    /// The payload couldn't be serialized to the codec's wire format
+    Encode(String),
+    /// The bytes couldn't be deserialized as a `JobPayload`
+    Decode(String),
+    /// Decoding succeeded, but the payload's `schema_version` isn't one this
+    /// build knows how to process
+    UnsupportedSchemaVersion(String),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodecError::Encode(msg) => write!(f, "failed to encode payload: {}", msg),
+            CodecError::Decode(msg) => write!(f, "failed to decode payload: {}", msg),
+            CodecError::UnsupportedSchemaVersion(version) => {
+                write!(f, "unsupported schema_version '{}' on the wire", version)
+            }
+        }
+    }
+}
+
+impl StdError for CodecError {}
+
+/// Encodes/decodes a [`JobPayload`] to and from a backend's wire format
+///
+/// Implementations should reject a decoded payload whose `schema_version`
+/// doesn't match [`SUPPORTED_SCHEMA_VERSION`] with
+/// [`CodecError::UnsupportedSchemaVersion`] rather than handing a worker a
+/// shape it doesn't know how to process — see [`gate_schema_version`].
+pub trait PayloadCodec: Send + Sync {
+    /// Serialize `payload` to this codec's wire format
+    fn encode(&self, payload: &JobPayload) -> Result<Vec<u8>, CodecError>;
+
+    /// Deserialize `bytes` back into a `JobPayload`
+    fn decode(&self, bytes: &[u8]) -> Result<JobPayload, CodecError>;
+}
+
+/// Reject `payload` if its `schema_version` isn't [`SUPPORTED_SCHEMA_VERSION`]
+///
+/// Shared by every [`PayloadCodec::decode`] implementation so an
+/// old-or-future payload shape is caught at the wire boundary instead of
+/// surfacing as a confusing deserialization error deeper in a worker.
+fn gate_schema_version(payload: JobPayload) -> Result<JobPayload, CodecError> {
+    if payload.schema_version != SUPPORTED_SCHEMA_VERSION {
+        return Err(CodecError::UnsupportedSchemaVersion(payload.schema_version));
+    }
+    Ok(payload)
+}
+
+/// Human-readable JSON codec — the default, for backward compatibility with
+/// payloads already on the wire and for ad-hoc debugging/interop
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonCodec;
+
+impl PayloadCodec for JsonCodec {
+    fn encode(&self, payload: &JobPayload) -> Result<Vec<u8>, CodecError> {
+        serde_json::to_vec(payload).map_err(|e| CodecError::Encode(e.to_string()))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<JobPayload, CodecError> {
+        let payload: JobPayload =
+            serde_json::from_slice(bytes).map_err(|e| CodecError::Decode(e.to_string()))?;
+        gate_schema_version(payload)
+    }
+}
+
+/// On-the-wire shape [`BincodeCodec`] actually encodes
+///
+/// Identical to [`JobPayload`] except `metadata` is carried as a
+/// pre-serialized JSON string rather than `serde_json::Value` directly.
+/// `Value`'s `Deserialize` impl relies on `deserialize_any`, which bincode's
+/// deserializer explicitly doesn't support — encoding would succeed but
+/// every decode would error. Round-tripping `metadata` through a `String`
+/// keeps it self-describing without forcing the rest of the struct through
+/// the same indirection. `scheduled_at`/`priority` are plain fields here —
+/// unlike `metadata`/`Value`, bincode has no trouble with them directly.
+#[derive(Serialize, Deserialize)]
+struct BincodeWire {
+    job_id: String,
+    user_id: Uuid,
+    question: String,
+    card_count: u32,
+    schema_version: String,
+    prompt_version: String,
+    dedupe_key: Option<String>,
+    trace_id: Option<String>,
+    created_at: DateTime<Utc>,
+    scheduled_at: Option<DateTime<Utc>>,
+    priority: u8,
+    metadata_json: String,
+}
+
+impl BincodeWire {
+    fn from_payload(payload: &JobPayload) -> Result<Self, CodecError> {
+        Ok(Self {
+            job_id: payload.job_id.clone(),
+            user_id: payload.user_id,
+            question: payload.question.clone(),
+            card_count: payload.card_count,
+            schema_version: payload.schema_version.clone(),
+            prompt_version: payload.prompt_version.clone(),
+            dedupe_key: payload.dedupe_key.clone(),
+            trace_id: payload.trace_id.clone(),
+            created_at: payload.created_at,
+            scheduled_at: payload.scheduled_at,
+            priority: payload.priority,
+            metadata_json: serde_json::to_string(&payload.metadata)
+                .map_err(|e| CodecError::Encode(e.to_string()))?,
+        })
+    }
+
+    fn into_payload(self) -> Result<JobPayload, CodecError> {
+        Ok(JobPayload {
+            job_id: self.job_id,
+            user_id: self.user_id,
+            question: self.question,
+            card_count: self.card_count,
+            schema_version: self.schema_version,
+            prompt_version: self.prompt_version,
+            dedupe_key: self.dedupe_key,
+            trace_id: self.trace_id,
+            created_at: self.created_at,
+            scheduled_at: self.scheduled_at,
+            priority: self.priority,
+            metadata: serde_json::from_str(&self.metadata_json)
+                .map_err(|e| CodecError::Decode(e.to_string()))?,
+        })
+    }
+}
+
+/// Compact binary codec (`bincode`), for deployments where enqueue/dequeue
+/// throughput matters more than wire readability
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BincodeCodec;
+
+impl PayloadCodec for BincodeCodec {
+    fn encode(&self, payload: &JobPayload) -> Result<Vec<u8>, CodecError> {
+        let wire = BincodeWire::from_payload(payload)?;
+        bincode::serialize(&wire).map_err(|e| CodecError::Encode(e.to_string()))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<JobPayload, CodecError> {
+        let wire: BincodeWire =
+            bincode::deserialize(bytes).map_err(|e| CodecError::Decode(e.to_string()))?;
+        let payload = wire.into_payload()?;
+        gate_schema_version(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::time::Instant;
+    use uuid::Uuid;
+
+    fn sample_payload() -> JobPayload {
+        JobPayload {
+            job_id: Uuid::new_v4().to_string(),
+            user_id: Uuid::new_v4(),
+            question: "What does the future hold?".to_string(),
+            card_count: 3,
+            schema_version: SUPPORTED_SCHEMA_VERSION.to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: Some("trace-abc".to_string()),
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({"locale": "th", "source": "mobile"}),
+        }
+    }
+
+    #[test]
+    fn test_json_codec_round_trips() {
+        let payload = sample_payload();
+        let codec = JsonCodec;
+
+        let bytes = codec.encode(&payload).unwrap();
+        let decoded = codec.decode(&bytes).unwrap();
+
+        assert_eq!(decoded.job_id, payload.job_id);
+        assert_eq!(decoded.question, payload.question);
+    }
+
+    #[test]
+    fn test_bincode_codec_round_trips() {
+        let payload = sample_payload();
+        let codec = BincodeCodec;
+
+        let bytes = codec.encode(&payload).unwrap();
+        let decoded = codec.decode(&bytes).unwrap();
+
+        assert_eq!(decoded.job_id, payload.job_id);
+        assert_eq!(decoded.question, payload.question);
+    }
+
+    #[test]
+    fn test_bincode_codec_round_trips_scheduled_at_and_priority() {
+        let mut payload = sample_payload();
+        payload.scheduled_at = Some(Utc::now() + chrono::Duration::minutes(5));
+        payload.priority = 9;
+        let codec = BincodeCodec;
+
+        let bytes = codec.encode(&payload).unwrap();
+        let decoded = codec.decode(&bytes).unwrap();
+
+        assert_eq!(decoded.scheduled_at, payload.scheduled_at);
+        assert_eq!(decoded.priority, payload.priority);
+    }
+
+    #[test]
+    fn test_decode_rejects_unsupported_schema_version() {
+        let mut payload = sample_payload();
+        payload.schema_version = "999".to_string();
+
+        let json_codec = JsonCodec;
+        let bytes = json_codec.encode(&payload).unwrap();
+        match json_codec.decode(&bytes) {
+            Err(CodecError::UnsupportedSchemaVersion(v)) => assert_eq!(v, "999"),
+            other => panic!("expected UnsupportedSchemaVersion, got {:?}", other),
+        }
+
+        let bincode_codec = BincodeCodec;
+        let bytes = bincode_codec.encode(&payload).unwrap();
+        match bincode_codec.decode(&bytes) {
+            Err(CodecError::UnsupportedSchemaVersion(v)) => assert_eq!(v, "999"),
+            other => panic!("expected UnsupportedSchemaVersion, got {:?}", other),
+        }
+    }
+
+    /// Benchmark-style comparison: not a hard performance assertion (wall
+    /// clock is too noisy in CI for that), but round-trips a few thousand
+    /// payloads through both codecs and prints the timing so the throughput
+    /// difference bincode is meant to buy is visible when run with
+    /// `--nocapture`.
+    #[test]
+    fn test_bincode_and_json_round_trip_timing_at_scale() {
+        const N: usize = 5_000;
+        let payloads: Vec<JobPayload> = (0..N).map(|_| sample_payload()).collect();
+
+        let json_codec = JsonCodec;
+        let json_start = Instant::now();
+        for payload in &payloads {
+            let bytes = json_codec.encode(payload).unwrap();
+            let _ = json_codec.decode(&bytes).unwrap();
+        }
+        let json_elapsed = json_start.elapsed();
+
+        let bincode_codec = BincodeCodec;
+        let bincode_start = Instant::now();
+        for payload in &payloads {
+            let bytes = bincode_codec.encode(payload).unwrap();
+            let _ = bincode_codec.decode(&bytes).unwrap();
+        }
+        let bincode_elapsed = bincode_start.elapsed();
+
+        println!(
+            "encode+decode {} payloads: json={:?}, bincode={:?}",
+            N, json_elapsed, bincode_elapsed
+        );
+    }
+}
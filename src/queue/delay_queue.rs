@@ -0,0 +1,132 @@
+//! Generic binary-heap-ordered delay queue
+//!
+//! Backs the "not due yet" staging areas used by
+//! [`crate::queue::inmemory_queue::InMemoryQueue`] — jobs scheduled via
+//! `enqueue_at`/`enqueue_delayed`, and jobs `nack`'d with a backoff delay —
+//! so promoting due entries is a heap-pop instead of a linear scan over a
+//! `Vec`.
+
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// A `T` staged until `ready_at`, ordered so the earliest deadline sorts
+/// first out of the backing [`BinaryHeap`] (a max-heap by default, so
+/// [`Ord`] is reversed on `ready_at`)
+struct DelayedEntry<T> {
+    ready_at: DateTime<Utc>,
+    item: T,
+}
+
+impl<T> PartialEq for DelayedEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ready_at == other.ready_at
+    }
+}
+
+impl<T> Eq for DelayedEntry<T> {}
+
+impl<T> PartialOrd for DelayedEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for DelayedEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.ready_at.cmp(&self.ready_at)
+    }
+}
+
+/// A set of `T`s, each staged until its own `ready_at` time, ordered by a
+/// binary heap so the next-due entry is always a cheap peek/pop away
+///
+/// There is no capacity limit: [`DelayQueue::push`] always accepts the entry.
+pub struct DelayQueue<T> {
+    heap: BinaryHeap<DelayedEntry<T>>,
+}
+
+impl<T> Default for DelayQueue<T> {
+    fn default() -> Self {
+        Self {
+            heap: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<T> DelayQueue<T> {
+    /// Create an empty delay queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stage `item` until `ready_at`
+    pub fn push(&mut self, ready_at: DateTime<Utc>, item: T) {
+        self.heap.push(DelayedEntry { ready_at, item });
+    }
+
+    /// Number of entries currently staged, ready or not
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Whether any entries are currently staged
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Pop and return every entry whose `ready_at` is `<= now`, earliest
+    /// first, leaving entries that aren't yet due untouched
+    ///
+    /// Lets tests (and [`crate::queue::test_harness::TestQueueHarness`]) force
+    /// due entries to surface deterministically instead of sleeping and
+    /// hoping a background timer has run.
+    pub fn poll_ready(&mut self, now: DateTime<Utc>) -> Vec<T> {
+        let mut ready = Vec::new();
+        while let Some(top) = self.heap.peek() {
+            if top.ready_at > now {
+                break;
+            }
+            ready.push(self.heap.pop().expect("just peeked Some").item);
+        }
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_poll_ready_returns_only_due_entries_earliest_first() {
+        let now = Utc::now();
+        let mut queue = DelayQueue::new();
+        queue.push(now + Duration::seconds(10), "late");
+        queue.push(now - Duration::seconds(5), "early");
+        queue.push(now - Duration::seconds(1), "mid");
+
+        let ready = queue.poll_ready(now);
+
+        assert_eq!(ready, vec!["early", "mid"]);
+        assert_eq!(queue.len(), 1);
+    }
+
+    #[test]
+    fn test_poll_ready_on_empty_queue_returns_empty() {
+        let mut queue: DelayQueue<&str> = DelayQueue::new();
+        assert!(queue.poll_ready(Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn test_is_empty_reflects_remaining_entries() {
+        let mut queue = DelayQueue::new();
+        assert!(queue.is_empty());
+
+        queue.push(Utc::now(), "job");
+        assert!(!queue.is_empty());
+
+        queue.poll_ready(Utc::now() + Duration::seconds(1));
+        assert!(queue.is_empty());
+    }
+}
@@ -7,7 +7,7 @@
 //! # Features
 //!
 //! - Thread-safe concurrent access using Arc/Mutex
-//! - FIFO job ordering
+//! - Priority-ordered dequeue, FIFO among jobs of equal priority
 //! - Job acknowledgement and negative acknowledgement
 //! - No external dependencies (only stdlib + tokio)
 //!
@@ -16,11 +16,64 @@
 //! The InMemoryQueue uses Arc<Mutex<>> internally to ensure thread-safe
 //! concurrent access from multiple workers/consumers.
 
-use crate::queue::{JobPayload, Queue, QueuedJob};
+use crate::error::{QueueError, WorkerError};
+use crate::queue::delay_queue::DelayQueue;
+use crate::queue::{DeadLetterEntry, JobPayload, Queue, QueuedJob};
 use async_trait::async_trait;
-use std::collections::{HashMap, VecDeque};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 use std::error::Error;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A job waiting in [`QueueState::pending`], ordered for priority dequeue
+///
+/// [`BinaryHeap`] is a max-heap, so `Ord` is defined so the job that should
+/// be dequeued next compares greatest: higher [`JobPayload::priority`] wins,
+/// and on a tie the lower `seq` (the one enqueued earlier) wins, preserving
+/// FIFO order among equal-priority jobs.
+struct PendingEntry {
+    seq: u64,
+    priority: u8,
+    payload: JobPayload,
+}
+
+impl PartialEq for PendingEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority && self.seq == other.seq
+    }
+}
+
+impl Eq for PendingEntry {}
+
+impl PartialOrd for PendingEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+/// Default visibility timeout: how long a claim survives without a
+/// [`Queue::heartbeat`] before it's considered abandoned and reclaimed
+const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default number of delivery attempts before `nack` dead-letters a job
+/// instead of requeueing it
+const DEFAULT_MAX_RETRIES: u32 = 5;
+
+/// Default base delay for the exponential backoff applied to requeued jobs
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+/// Default cap on the exponential backoff applied to requeued jobs
+const DEFAULT_RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
 
 /// Internal state for the in-memory queue
 ///
@@ -28,8 +81,13 @@ use std::sync::{Arc, Mutex};
 /// for thread-safe concurrent access.
 #[derive(Default)]
 struct QueueState {
-    /// Pending jobs waiting to be processed (FIFO queue)
-    pending: VecDeque<JobPayload>,
+    /// Pending jobs waiting to be processed, ordered by
+    /// `(priority desc, enqueued order asc)` — see [`PendingEntry`]
+    pending: BinaryHeap<PendingEntry>,
+
+    /// Monotonic counter assigning each [`PendingEntry`] its `seq`, so
+    /// equal-priority jobs still dequeue in FIFO order
+    next_seq: u64,
 
     /// Jobs currently being processed by consumers
     /// Maps job_id -> (consumer_id, job_data)
@@ -38,12 +96,93 @@ struct QueueState {
     /// Track job attempts for retry logic
     /// Maps job_id -> attempt_count
     attempts: HashMap<String, u32>,
+
+    /// Dead Letter Queue entries, keyed by job_id
+    dlq: HashMap<String, DeadLetterEntry>,
+
+    /// Jobs scheduled for future delivery via `enqueue_at`/`enqueue_delayed`,
+    /// and jobs backed off by `nack`, staged outside `pending` until their
+    /// run time arrives
+    delayed: DelayQueue<JobPayload>,
+
+    /// Visibility deadline for each claim in `processing`, keyed by job_id
+    ///
+    /// Set on [`Queue::dequeue`] and refreshed on [`Queue::heartbeat`]; a
+    /// claim whose deadline has passed is reclaimed back to `pending` by
+    /// [`reclaim_expired`] the next time `dequeue` runs.
+    deadlines: HashMap<String, DateTime<Utc>>,
+
+    /// Maps a `JobPayload::dedupe_key` to the job_id currently holding it,
+    /// for every job admitted through `enqueue` that's still active (pending,
+    /// backed off by `nack`, or processing); jobs staged with `enqueue_at`
+    /// and jobs re-enqueued by `replay_dlq` are not indexed
+    ///
+    /// Cleared when that job reaches a terminal state (`ack` or
+    /// `move_to_dlq`), at which point the key becomes available again for a
+    /// fresh enqueue.
+    dedupe_index: HashMap<String, String>,
+}
+
+/// Push `payload` onto `pending`, assigning it the next FIFO tie-break
+/// sequence number and reading its priority via [`JobPayload::priority`]
+fn push_pending(state: &mut QueueState, payload: JobPayload) {
+    let seq = state.next_seq;
+    state.next_seq += 1;
+    let priority = payload.priority();
+    state.pending.push(PendingEntry {
+        seq,
+        priority,
+        payload,
+    });
+}
+
+/// Move any `delayed` entries whose run time has passed into `pending`
+///
+/// Called at the top of `dequeue` so callers never need a separate
+/// "promote due jobs" API for this backend.
+fn promote_due_jobs(state: &mut QueueState) {
+    let now = Utc::now();
+    for payload in state.delayed.poll_ready(now) {
+        state.attempts.entry(payload.job_id.clone()).or_insert(0);
+        push_pending(state, payload);
+    }
+}
+
+/// Move any `processing` claims whose visibility deadline has passed back
+/// into `pending`, so a crashed/stalled worker doesn't strand a job forever
+///
+/// Attempt counts are left untouched here; `dequeue` increments them again
+/// when the job is re-claimed, same as a [`Queue::nack`]'d job would be.
+/// Returns the number of claims reclaimed.
+fn reclaim_expired(state: &mut QueueState) -> usize {
+    let now = Utc::now();
+    let expired: Vec<String> = state
+        .deadlines
+        .iter()
+        .filter(|(_, deadline)| **deadline <= now)
+        .map(|(job_id, _)| job_id.clone())
+        .collect();
+
+    let mut reclaimed = 0;
+    for job_id in expired {
+        state.deadlines.remove(&job_id);
+        if let Some((_, queued_job)) = state.processing.remove(&job_id) {
+            println!(
+                "Reclaiming job {} whose visibility timeout expired",
+                job_id
+            );
+            push_pending(state, queued_job.payload);
+            reclaimed += 1;
+        }
+    }
+    reclaimed
 }
 
 /// In-memory queue implementation
 ///
 /// Provides a thread-safe, in-memory job queue suitable for testing.
-/// Uses VecDeque for FIFO ordering and HashMap for tracking processing jobs.
+/// Uses a BinaryHeap keyed on `(priority, enqueued order)` for dequeue
+/// ordering (see [`PendingEntry`]) and HashMap for tracking processing jobs.
 ///
 /// # Example
 ///
@@ -59,6 +198,18 @@ struct QueueState {
 pub struct InMemoryQueue {
     /// Shared state protected by Mutex for thread safety
     state: Arc<Mutex<QueueState>>,
+
+    /// How long a claim survives without a heartbeat before being reclaimed
+    visibility_timeout: Duration,
+
+    /// How many delivery attempts a job gets before `nack` dead-letters it
+    max_retries: u32,
+
+    /// Base delay for the exponential backoff `nack` applies when requeueing
+    retry_base_delay: Duration,
+
+    /// Cap on the exponential backoff `nack` applies when requeueing
+    retry_max_delay: Duration,
 }
 
 impl InMemoryQueue {
@@ -70,8 +221,73 @@ impl InMemoryQueue {
     pub fn new() -> Self {
         Self {
             state: Arc::new(Mutex::new(QueueState::default())),
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+            retry_max_delay: DEFAULT_RETRY_MAX_DELAY,
         }
     }
+
+    /// Create a new in-memory queue with a custom visibility timeout
+    pub fn with_visibility_timeout(visibility_timeout: Duration) -> Self {
+        Self {
+            visibility_timeout,
+            ..Self::new()
+        }
+    }
+
+    /// Create a new in-memory queue with a custom max-retries/backoff policy
+    pub fn with_retry_config(
+        max_retries: u32,
+        retry_base_delay: Duration,
+        retry_max_delay: Duration,
+    ) -> Self {
+        Self {
+            max_retries,
+            retry_base_delay,
+            retry_max_delay,
+            ..Self::new()
+        }
+    }
+
+    /// Remove every entry from the Dead Letter Queue and return it
+    ///
+    /// Unlike [`Queue::replay_dlq`], this does not re-enqueue anything — it's
+    /// for operators inspecting/exporting dead-lettered jobs themselves.
+    pub async fn drain_dead_letter(&self) -> Result<Vec<(JobPayload, String)>, Box<dyn Error>> {
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        Ok(state
+            .dlq
+            .drain()
+            .map(|(_, entry)| (entry.payload, entry.error))
+            .collect())
+    }
+
+    /// Force every currently-due `delayed` entry (from `enqueue_at`,
+    /// `enqueue_delayed`, or a `nack` backoff) into `pending` right now
+    ///
+    /// `dequeue` already does this implicitly, but tests that only want to
+    /// assert on queue/DLQ state without also dequeuing can call this
+    /// directly to advance the clock deterministically. Returns the number
+    /// of jobs promoted.
+    pub async fn poll_ready(&self) -> Result<usize, Box<dyn Error>> {
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        let before = state.pending.len();
+        promote_due_jobs(&mut state);
+        Ok(state.pending.len() - before)
+    }
 }
 
 impl Default for InMemoryQueue {
@@ -84,7 +300,11 @@ impl Default for InMemoryQueue {
 impl Queue for InMemoryQueue {
     /// Enqueue a job to the in-memory queue
     ///
-    /// Adds the job to the pending queue in FIFO order.
+    /// Adds the job to the pending queue, ordered for dequeue by
+    /// [`JobPayload::priority`] (highest first) and FIFO among jobs of equal
+    /// priority. If `payload` carries a `dedupe_key` that's already held by
+    /// another active (pending or processing) job, this is a no-op that
+    /// returns the existing job's id instead of enqueueing a duplicate.
     /// Thread-safe for concurrent access.
     async fn enqueue(&self, payload: JobPayload) -> Result<String, Box<dyn Error>> {
         let job_id = payload.job_id.clone();
@@ -96,19 +316,34 @@ impl Queue for InMemoryQueue {
             )))
         })?;
 
-        // Add to pending queue
-        state.pending.push_back(payload);
+        if let Some(ref dedupe_key) = payload.dedupe_key {
+            if let Some(existing_job_id) = state.dedupe_index.get(dedupe_key) {
+                return Ok(existing_job_id.clone());
+            }
+        }
+
+        if let Some(ref dedupe_key) = payload.dedupe_key {
+            state
+                .dedupe_index
+                .insert(dedupe_key.clone(), job_id.clone());
+        }
 
         // Initialize attempt counter
         state.attempts.entry(job_id.clone()).or_insert(0);
 
+        // Add to pending queue
+        push_pending(&mut state, payload);
+
         Ok(job_id)
     }
 
     /// Dequeue a job from the in-memory queue
     ///
     /// Retrieves the next job from the pending queue and marks it as
-    /// being processed by the specified consumer.
+    /// being processed by the specified consumer. A payload that fails
+    /// [`JobPayload::validate`] is a poison message: it's routed straight to
+    /// the Dead Letter Queue instead of being handed to a worker, so it
+    /// can't block the FIFO queue behind it or loop through retries forever.
     async fn dequeue(&self, consumer_id: &str) -> Result<Option<QueuedJob>, Box<dyn Error>> {
         let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
             Box::new(std::io::Error::other(format!(
@@ -117,10 +352,39 @@ impl Queue for InMemoryQueue {
             )))
         })?;
 
-        // Get next job from pending queue
-        if let Some(payload) = state.pending.pop_front() {
+        let _ = reclaim_expired(&mut state);
+        promote_due_jobs(&mut state);
+
+        // Get next job from pending queue, skipping (and dead-lettering) any
+        // poison payloads along the way.
+        while let Some(entry) = state.pending.pop() {
+            let payload = entry.payload;
             let job_id = payload.job_id.clone();
 
+            if let Err(reason) = payload.validate() {
+                let error = QueueError::InvalidJob {
+                    job_id: job_id.clone(),
+                    reason: reason.clone(),
+                };
+                println!("{}", error);
+
+                state.attempts.remove(&job_id);
+                if let Some(ref dedupe_key) = payload.dedupe_key {
+                    state.dedupe_index.remove(dedupe_key);
+                }
+                state.dlq.insert(
+                    job_id.clone(),
+                    DeadLetterEntry {
+                        job_id: job_id.clone(),
+                        payload,
+                        error: reason,
+                        attempts: 0,
+                        failed_at: Utc::now(),
+                    },
+                );
+                continue;
+            }
+
             // Increment attempt counter
             let attempts = state
                 .attempts
@@ -141,12 +405,18 @@ impl Queue for InMemoryQueue {
                 job_id.clone(),
                 (consumer_id.to_string(), queued_job.clone()),
             );
+            state.deadlines.insert(
+                job_id.clone(),
+                Utc::now()
+                    + chrono::Duration::from_std(self.visibility_timeout)
+                        .unwrap_or_else(|_| chrono::Duration::seconds(30)),
+            );
 
-            Ok(Some(queued_job))
-        } else {
-            // No jobs available
-            Ok(None)
+            return Ok(Some(queued_job));
         }
+
+        // No (valid) jobs available
+        Ok(None)
     }
 
     /// Acknowledge successful job completion
@@ -161,7 +431,11 @@ impl Queue for InMemoryQueue {
         })?;
 
         // Remove from processing map
-        if let Some((processing_consumer, _)) = state.processing.get(job_id) {
+        if let Some((processing_consumer, queued_job)) = state.processing.get(job_id) {
+            if let Some(ref dedupe_key) = queued_job.payload.dedupe_key {
+                state.dedupe_index.remove(dedupe_key);
+            }
+
             // Verify the consumer ID matches (optional security check)
             if processing_consumer == consumer_id {
                 state.processing.remove(job_id);
@@ -172,6 +446,7 @@ impl Queue for InMemoryQueue {
                 state.processing.remove(job_id);
                 state.attempts.remove(job_id);
             }
+            state.deadlines.remove(job_id);
         }
         // If job not found in processing, it's a duplicate ACK - just ignore it
 
@@ -180,7 +455,10 @@ impl Queue for InMemoryQueue {
 
     /// Negative acknowledgement - job failed
     ///
-    /// Requeues the job for retry by moving it back to the pending queue.
+    /// Requeues the job with an exponential backoff delay (staged in
+    /// `delayed`, same as `enqueue_at`) unless its attempt count has reached
+    /// `max_retries`, in which case it's moved to the Dead Letter Queue
+    /// instead of being requeued again.
     async fn nack(
         &self,
         job_id: &str,
@@ -195,6 +473,7 @@ impl Queue for InMemoryQueue {
         })?;
 
         // Find job in processing map
+        state.deadlines.remove(job_id);
         if let Some((processing_consumer, queued_job)) = state.processing.remove(job_id) {
             // Verify consumer ID (optional)
             if processing_consumer != consumer_id {
@@ -210,9 +489,39 @@ impl Queue for InMemoryQueue {
                 eprintln!("Job {} NACK'd by {}: {}", job_id, consumer_id, r);
             }
 
-            // Requeue the job by adding it back to pending
-            // Put it at the front for immediate retry (could also go to back)
-            state.pending.push_front(queued_job.payload);
+            if queued_job.attempts >= self.max_retries {
+                let error = WorkerError::MaxRetriesExceeded {
+                    job_id: job_id.to_string(),
+                    total_attempts: queued_job.attempts,
+                };
+                println!("{}", error);
+
+                state.attempts.remove(job_id);
+                if let Some(ref dedupe_key) = queued_job.payload.dedupe_key {
+                    state.dedupe_index.remove(dedupe_key);
+                }
+                state.dlq.insert(
+                    job_id.to_string(),
+                    DeadLetterEntry {
+                        job_id: job_id.to_string(),
+                        payload: queued_job.payload,
+                        error: reason.unwrap_or_else(|| error.to_string()),
+                        attempts: queued_job.attempts,
+                        failed_at: Utc::now(),
+                    },
+                );
+            } else {
+                let delay = self
+                    .retry_base_delay
+                    .checked_mul(2u32.saturating_pow(queued_job.attempts))
+                    .unwrap_or(self.retry_max_delay)
+                    .min(self.retry_max_delay);
+
+                let not_before = Utc::now()
+                    + chrono::Duration::from_std(delay)
+                        .unwrap_or_else(|_| chrono::Duration::seconds(0));
+                state.delayed.push(not_before, queued_job.payload);
+            }
         }
 
         Ok(())
@@ -231,6 +540,192 @@ impl Queue for InMemoryQueue {
 
         Ok(state.pending.len())
     }
+
+    /// Move a job to the Dead Letter Queue
+    ///
+    /// Clears any in-flight processing/attempt tracking for the job and
+    /// records it in the DLQ map, keyed by job_id for O(1) replay.
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>> {
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        state.processing.remove(&job.job_id);
+        state.attempts.remove(&job.job_id);
+        state.deadlines.remove(&job.job_id);
+        if let Some(ref dedupe_key) = job.payload.dedupe_key {
+            state.dedupe_index.remove(dedupe_key);
+        }
+
+        state.dlq.insert(
+            job.job_id.clone(),
+            DeadLetterEntry {
+                job_id: job.job_id.clone(),
+                payload: job.payload.clone(),
+                error: reason,
+                attempts: job.attempts,
+                failed_at: chrono::Utc::now(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// List up to `limit` jobs currently sitting in the Dead Letter Queue
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+        let state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        Ok(state.dlq.values().take(limit).cloned().collect())
+    }
+
+    /// Look up a single Dead Letter Queue entry by `job_id`
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        let state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        Ok(state.dlq.get(job_id).cloned())
+    }
+
+    /// Number of entries currently sitting in the Dead Letter Queue
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        let state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        Ok(state.dlq.len())
+    }
+
+    /// Re-enqueue a dead job, resetting its attempt counter
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        let entry = state
+            .dlq
+            .remove(job_id)
+            .ok_or_else(|| -> Box<dyn Error> {
+                Box::new(std::io::Error::other(format!(
+                    "No DLQ entry found for job {}",
+                    job_id
+                )))
+            })?;
+
+        state.attempts.insert(entry.job_id.clone(), 0);
+        push_pending(&mut state, entry.payload);
+
+        Ok(())
+    }
+
+    /// Schedule a job to become available no earlier than `when`
+    ///
+    /// Stages the payload in `delayed` rather than `pending`; it's
+    /// promoted the next time `dequeue` runs and finds it due.
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>> {
+        let job_id = payload.job_id.clone();
+
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        state.delayed.push(when, payload);
+
+        Ok(job_id)
+    }
+
+    /// Extend the visibility deadline on a live claim
+    ///
+    /// No-ops if `job_id` isn't currently claimed by `consumer_id` (already
+    /// acked/nacked, or already reclaimed by [`reclaim_expired`]), per the
+    /// trait's documented contract.
+    async fn heartbeat(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        let claimed_by_consumer = state
+            .processing
+            .get(job_id)
+            .is_some_and(|(owner, _)| owner == consumer_id);
+
+        if claimed_by_consumer {
+            state.deadlines.insert(
+                job_id.to_string(),
+                Utc::now()
+                    + chrono::Duration::from_std(self.visibility_timeout)
+                        .unwrap_or_else(|_| chrono::Duration::seconds(30)),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Force a sweep of expired claims right now
+    ///
+    /// [`Queue::dequeue`] already sweeps opportunistically before claiming a
+    /// new job; this lets a caller that isn't actively dequeuing (e.g.
+    /// [`InMemoryQueue::spawn_reclaimer`]) trigger the same sweep directly.
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+        let mut state = self.state.lock().map_err(|e| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "Failed to acquire lock: {}",
+                e
+            )))
+        })?;
+
+        Ok(reclaim_expired(&mut state))
+    }
+}
+
+impl InMemoryQueue {
+    /// Spawn a background Tokio task that periodically calls
+    /// [`Queue::reclaim_expired`] to recover claims abandoned by crashed
+    /// workers, mirroring [`crate::queue::redis_queue::RedisQueue::spawn_reaper`]
+    ///
+    /// Returns the task's `JoinHandle` so callers can abort it on shutdown.
+    pub fn spawn_reclaimer(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match Queue::reclaim_expired(&*self).await {
+                    Ok(count) if count > 0 => {
+                        println!("Reclaimer recovered {} expired claim(s)", count);
+                    }
+                    Ok(_) => {}
+                    Err(e) => eprintln!("Failed to reclaim expired claims: {}", e),
+                }
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +745,8 @@ mod tests {
             dedupe_key: None,
             trace_id: None,
             created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
             metadata: serde_json::json!({}),
         }
     }
@@ -307,8 +804,13 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_nack_requeues_job() {
-        let queue = InMemoryQueue::new();
+    async fn test_nack_requeues_job_after_backoff_delay() {
+        // Base delay large enough that the job isn't immediately due.
+        let queue = InMemoryQueue::with_retry_config(
+            DEFAULT_MAX_RETRIES,
+            Duration::from_millis(50),
+            DEFAULT_RETRY_MAX_DELAY,
+        );
         let payload = create_test_payload("test");
         let job_id = payload.job_id.clone();
 
@@ -319,8 +821,262 @@ mod tests {
             .await
             .unwrap();
 
-        // Job should be back in queue
+        // Not yet due: staged in `delayed`, not visible in `pending`.
+        assert!(queue.dequeue("worker-2").await.unwrap().is_none());
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+
+        let job = queue.dequeue("worker-2").await.unwrap().unwrap();
+        assert_eq!(job.job_id, job_id);
+    }
+
+    #[tokio::test]
+    async fn test_nack_dead_letters_job_once_max_retries_reached() {
+        let queue = InMemoryQueue::with_retry_config(
+            1,
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+        );
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.attempts, 1);
+
+        queue
+            .nack(&job_id, "worker-1", Some("boom".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 1);
+
+        let drained = queue.drain_dead_letter().await.unwrap();
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].0.job_id, job_id);
+        assert_eq!(drained[0].1, "boom");
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dequeue_dead_letters_poison_payload_without_blocking_queue() {
+        let queue = InMemoryQueue::new();
+
+        let mut poison = create_test_payload("poisoned");
+        poison.card_count = 4; // not a supported spread size
+        let poison_id = poison.job_id.clone();
+
+        let good = create_test_payload("good");
+        let good_id = good.job_id.clone();
+
+        queue.enqueue(poison).await.unwrap();
+        queue.enqueue(good).await.unwrap();
+
+        // The poison payload is skipped and dead-lettered; the good job
+        // behind it is still dequeueable.
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, good_id);
+
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 1);
+        let drained = queue.drain_dead_letter().await.unwrap();
+        assert_eq!(drained[0].0.job_id, poison_id);
+    }
+
+    #[tokio::test]
+    async fn test_move_to_dlq_records_reason_and_clears_processing() {
+        let queue = InMemoryQueue::new();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+
+        queue
+            .move_to_dlq(&job, "max attempts exceeded".to_string())
+            .await
+            .unwrap();
+
+        let dlq = queue.list_dlq(10).await.unwrap();
+        assert_eq!(dlq.len(), 1);
+        assert_eq!(dlq[0].job_id, job_id);
+        assert_eq!(dlq[0].error, "max attempts exceeded");
+        assert_eq!(dlq[0].attempts, job.attempts);
+
+        // The job should no longer show up in the normal queue length
+        let length = queue.get_queue_length().await.unwrap();
+        assert_eq!(length, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_dlq_respects_limit() {
+        let queue = InMemoryQueue::new();
+
+        for i in 0..3 {
+            let payload = create_test_payload(&format!("test-{}", i));
+            queue.enqueue(payload).await.unwrap();
+            let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+            queue
+                .move_to_dlq(&job, "failed".to_string())
+                .await
+                .unwrap();
+        }
+
+        let dlq = queue.list_dlq(2).await.unwrap();
+        assert_eq!(dlq.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_replay_dlq_reenqueues_with_reset_attempts() {
+        let queue = InMemoryQueue::new();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        queue
+            .move_to_dlq(&job, "timeout".to_string())
+            .await
+            .unwrap();
+
+        queue.replay_dlq(&job_id).await.unwrap();
+
+        assert!(queue.list_dlq(10).await.unwrap().is_empty());
         let length = queue.get_queue_length().await.unwrap();
         assert_eq!(length, 1);
+
+        let replayed = queue.dequeue("worker-2").await.unwrap().unwrap();
+        assert_eq!(replayed.job_id, job_id);
+        assert_eq!(replayed.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_replay_dlq_errors_when_job_not_found() {
+        let queue = InMemoryQueue::new();
+        let result = queue.replay_dlq("does-not-exist").await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_at_future_time_does_not_appear_in_queue_length() {
+        let queue = InMemoryQueue::new();
+        let payload = create_test_payload("test");
+
+        queue
+            .enqueue_at(payload, Utc::now() + chrono::Duration::hours(1))
+            .await
+            .unwrap();
+
+        let length = queue.get_queue_length().await.unwrap();
+        assert_eq!(length, 0);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_at_past_time_is_promoted_on_dequeue() {
+        let queue = InMemoryQueue::new();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue
+            .enqueue_at(payload, Utc::now() - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, job_id);
+        assert_eq!(job.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_delayed_promotes_once_delay_elapses() {
+        let queue = InMemoryQueue::new();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue
+            .enqueue_delayed(payload, std::time::Duration::from_millis(10))
+            .await
+            .unwrap();
+
+        assert!(queue.dequeue("worker-1").await.unwrap().is_none());
+
+        tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, job_id);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_with_same_dedupe_key_returns_existing_job_id() {
+        let queue = InMemoryQueue::new();
+        let mut payload = create_test_payload("duplicate?");
+        payload.dedupe_key = Some("user-123:reading".to_string());
+
+        let mut second = create_test_payload("duplicate?");
+        second.dedupe_key = payload.dedupe_key.clone();
+
+        let first_id = queue.enqueue(payload).await.unwrap();
+        let second_id = queue.enqueue(second).await.unwrap();
+
+        assert_eq!(first_id, second_id);
+        assert_eq!(queue.get_queue_length().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_key_freed_after_ack_allows_new_enqueue() {
+        let queue = InMemoryQueue::new();
+        let mut payload = create_test_payload("first");
+        payload.dedupe_key = Some("user-123:reading".to_string());
+        let first_id = queue.enqueue(payload).await.unwrap();
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        queue.ack(&job.job_id, "worker-1").await.unwrap();
+
+        let mut second = create_test_payload("second");
+        second.dedupe_key = Some("user-123:reading".to_string());
+        let second_id = queue.enqueue(second).await.unwrap();
+
+        assert_ne!(first_id, second_id);
+        assert_eq!(queue.get_queue_length().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dequeue_prefers_higher_priority_job_regardless_of_enqueue_order() {
+        let queue = InMemoryQueue::new();
+
+        let mut low = create_test_payload("low priority, enqueued first");
+        low.priority = 1;
+        let low_id = low.job_id.clone();
+
+        let mut high = create_test_payload("high priority, enqueued second");
+        high.priority = 9;
+        let high_id = high.job_id.clone();
+
+        queue.enqueue(low).await.unwrap();
+        queue.enqueue(high).await.unwrap();
+
+        let first = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(first.job_id, high_id);
+
+        let second = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(second.job_id, low_id);
+    }
+
+    #[tokio::test]
+    async fn test_dequeue_preserves_fifo_order_among_equal_priority_jobs() {
+        let queue = InMemoryQueue::new();
+
+        let first = create_test_payload("first");
+        let first_id = first.job_id.clone();
+        let second = create_test_payload("second");
+        let second_id = second.job_id.clone();
+
+        queue.enqueue(first).await.unwrap();
+        queue.enqueue(second).await.unwrap();
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, first_id);
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, second_id);
     }
 }
@@ -0,0 +1,88 @@
+//! Typed, self-describing job payloads with a registry of handlers
+//!
+//! `JobPayload`/`JobType` (see [`crate::queue::types`]) force every consumer
+//! to match on a fixed enum and hand-deserialize a known shape. This module
+//! is an additive, opt-in alternative for callers that want heterogeneous
+//! job kinds instead: a [`Job`] trait object serialized with a
+//! `type`-discriminator tag, and a [`JobRegistry`] mapping that tag back to a
+//! deserializer and handler. [`crate::queue::redis_queue::RedisQueue`]'s
+//! `enqueue_job`/`process_next` methods drive this path; it does not replace
+//! or interact with the `Queue` trait's `enqueue`/`dequeue`.
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+
+/// A polymorphic unit of work that knows how to run itself
+///
+/// Implementations are registered in a [`JobRegistry`] under a tag string;
+/// `RedisQueue::process_next` uses the tag embedded in the serialized
+/// payload to reconstruct the concrete type and invoke `run`.
+#[async_trait]
+pub trait Job: Send + Sync {
+    /// Execute this job's work
+    async fn run(&self) -> Result<(), Box<dyn Error>>;
+}
+
+/// Wire format for a tagged job: the discriminator plus its serialized body
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TaggedJob {
+    /// Discriminator identifying which registered type `body` deserializes as
+    pub tag: String,
+    /// The job's serialized fields
+    pub body: Value,
+}
+
+type JobFactory = Box<dyn Fn(Value) -> Result<Box<dyn Job>, Box<dyn Error>> + Send + Sync>;
+
+/// Maps a job's `tag` string to a deserializer + handler for it
+///
+/// Lets a queue carry heterogeneous job kinds without every consumer
+/// re-implementing a big match statement over a fixed enum.
+#[derive(Default)]
+pub struct JobRegistry {
+    factories: HashMap<String, JobFactory>,
+}
+
+impl JobRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a concrete job type under `tag`
+    ///
+    /// `J` must be both [`Job`] (so it can run) and deserializable (so the
+    /// registry can reconstruct it from a [`TaggedJob::body`]).
+    pub fn register<J>(&mut self, tag: &str)
+    where
+        J: Job + DeserializeOwned + 'static,
+    {
+        self.factories.insert(
+            tag.to_string(),
+            Box::new(move |body: Value| -> Result<Box<dyn Job>, Box<dyn Error>> {
+                let job: J = serde_json::from_value(body)?;
+                Ok(Box::new(job))
+            }),
+        );
+    }
+
+    /// Reconstruct the concrete job a [`TaggedJob`] describes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no type was registered under `tagged.tag`, or if
+    /// `tagged.body` doesn't deserialize as that type.
+    pub fn construct(&self, tagged: &TaggedJob) -> Result<Box<dyn Job>, Box<dyn Error>> {
+        let factory = self.factories.get(&tagged.tag).ok_or_else(|| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "No job type registered for tag '{}'",
+                tagged.tag
+            )))
+        })?;
+
+        factory(tagged.body.clone())
+    }
+}
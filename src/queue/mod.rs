@@ -2,15 +2,73 @@
 //!
 //! Provides a standardized interface for job queues that can be backed by
 //! different implementations (Redis, Upstash, In-Memory).
+//!
+//! The durable Redis-backed subsystem is a pair of [`Queue`] implementors
+//! selected at construction time (see [`build_redis_queue_from_env`]):
+//! [`redis_queue::RedisQueue`] enqueues via `XADD` and consumes via
+//! `XREADGROUP` under a named consumer group, acking with `XACK` and
+//! reclaiming a crashed consumer's stuck entries off the Pending Entries
+//! List with `XPENDING`/`XAUTOCLAIM`. For Redis deployments without
+//! Stream/consumer-group support (older servers, certain managed tiers),
+//! [`redis_list_queue::RedisListQueue`] provides the same guarantees on
+//! plain lists: `LPUSH`/`RPOPLPUSH` for claiming, a claim-timestamp sorted
+//! set standing in for the PEL, and a background reaper instead of
+//! `XAUTOCLAIM`. Both implement the same [`Queue`] trait so the rest of the
+//! pipeline doesn't need to know which one is active.
 
+pub mod codec;
+pub mod delay_queue;
+pub mod inmemory_queue;
+pub mod job_registry;
+pub mod notifying_queue;
+pub mod rate_limiter;
 pub mod redis_dedupe;
+pub mod redis_dedupe_events;
+pub mod redis_list_queue;
+pub mod redis_lock;
+pub mod redis_queue;
+pub mod sqlite_queue;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_harness;
+pub mod timed_queue;
 pub mod types;
+pub mod upstash_queue;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
+use std::time::Duration;
+
+/// Construct whichever Redis-backed `Queue` implementation `REDIS_BACKEND`
+/// selects
+///
+/// Reads `REDIS_BACKEND` (`"streams"`, the default, or `"list"`) so
+/// operators on managed Redis tiers or older servers without Stream/consumer
+/// group support can switch to [`redis_list_queue::RedisListQueue`] with a
+/// configuration change instead of a code change. Both backends implement
+/// [`Queue`], so callers that only hold a `Box<dyn Queue>` don't need to know
+/// which one is active.
+pub async fn build_redis_queue_from_env() -> Result<Box<dyn Queue>, Box<dyn Error>> {
+    let backend = std::env::var("REDIS_BACKEND").unwrap_or_else(|_| "streams".to_string());
+
+    match backend.as_str() {
+        "list" => {
+            let redis_url =
+                std::env::var("REDIS_URL").map_err(|_| "REDIS_URL environment variable not set")?;
+            let queue_name =
+                std::env::var("REDIS_STREAM_KEY").unwrap_or_else(|_| "tarot:jobs".to_string());
+            let queue = redis_list_queue::RedisListQueue::new(&redis_url, &queue_name).await?;
+            Ok(Box::new(queue))
+        }
+        _ => {
+            let queue = redis_queue::RedisQueue::from_env().await?;
+            Ok(Box::new(queue))
+        }
+    }
+}
 
-pub use types::{JobMetadata, JobPayload, JobType};
+pub use types::{DeadLetterEntry, JobMetadata, JobPayload, JobType};
 
 /// Job status enumeration
 ///
@@ -175,4 +233,122 @@ pub trait Queue: Send + Sync {
     /// println!("Queue has {} pending jobs", backlog);
     /// ```
     async fn get_queue_length(&self) -> Result<usize, Box<dyn Error>>;
+
+    /// Move a job to the Dead Letter Queue instead of retrying it
+    ///
+    /// Called by the worker loop when the retry policy declines a retry —
+    /// max attempts reached, a permanent error, or an exhausted token
+    /// budget — so the job is preserved with its failure context instead of
+    /// silently vanishing.
+    ///
+    /// # Arguments
+    ///
+    /// * `job` - The job being given up on
+    /// * `reason` - Human-readable description of the final failure
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>>;
+
+    /// List up to `limit` jobs currently sitting in the Dead Letter Queue
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>>;
+
+    /// Look up a single Dead Letter Queue entry by `job_id`
+    ///
+    /// The default implementation is a convenience wrapper over
+    /// [`Queue::list_dlq`] with no cap, so it pages in the entire DLQ just to
+    /// find one entry; implementations backed by a store that can point-look
+    /// up a single key (e.g. `HGET`/`WHERE job_id = ?`, every backend already
+    /// stores DLQ entries keyed by `job_id`) should override it instead of
+    /// relying on a capped bulk scan, which silently misses the entry once
+    /// the DLQ grows past that cap.
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        Ok(self
+            .list_dlq(usize::MAX)
+            .await?
+            .into_iter()
+            .find(|entry| entry.job_id == job_id))
+    }
+
+    /// Count how many jobs are currently sitting in the Dead Letter Queue
+    ///
+    /// The default implementation is a convenience wrapper over
+    /// [`Queue::list_dlq`]; implementations backed by a store that can report
+    /// this directly (e.g. `HLEN`/`COUNT`) should override it to avoid
+    /// materializing every DLQ entry just to count them.
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        Ok(self.list_dlq(usize::MAX).await?.len())
+    }
+
+    /// Re-enqueue a dead job, resetting its attempt counter
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The identifier of the DLQ entry to replay
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no DLQ entry exists for `job_id`.
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>>;
+
+    /// Schedule a job to become available no earlier than `when`
+    ///
+    /// Implementations stage the payload outside the main queue (e.g. a
+    /// sorted set scored by run time) until it's due, then promote it onto
+    /// the normal dequeue path. Returns the job's ID, same as [`Queue::enqueue`].
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The job data to be queued
+    /// * `when` - The earliest time the job should become available
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>>;
+
+    /// Schedule a job to become available after `delay` has elapsed
+    ///
+    /// Convenience wrapper over [`Queue::enqueue_at`] for the common
+    /// "run this N seconds from now" case.
+    async fn enqueue_delayed(
+        &self,
+        payload: JobPayload,
+        delay: Duration,
+    ) -> Result<String, Box<dyn Error>> {
+        let when = Utc::now()
+            + ChronoDuration::from_std(delay)
+                .map_err(|e| -> Box<dyn Error> { Box::new(std::io::Error::other(e)) })?;
+        self.enqueue_at(payload, when).await
+    }
+
+    /// Extend the visibility timeout on a job a worker is still processing
+    ///
+    /// Workers call this periodically while handling a long-running job so
+    /// that reclaim sweeps (e.g. [`crate::queue::redis_queue::RedisQueue::reclaim_stale`]
+    /// or an in-memory equivalent) don't mistake a slow-but-alive worker for a
+    /// crashed one and hand the job to someone else. `job_id` must currently
+    /// be claimed by `consumer_id`; implementations are free to no-op if the
+    /// job is no longer claimed (it may have already been acked, nacked, or
+    /// reclaimed) rather than erroring.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The job whose claim should be extended
+    /// * `consumer_id` - The worker asserting ownership of the claim
+    async fn heartbeat(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>>;
+
+    /// Sweep claims whose visibility timeout has expired back to pending
+    ///
+    /// A worker that dequeues a job and then crashes (or hangs) without
+    /// [`Queue::heartbeat`]-ing, [`Queue::ack`]-ing, or [`Queue::nack`]-ing
+    /// it would otherwise strand that job in `processing` forever. Calling
+    /// this moves any such abandoned claim back to pending (incrementing its
+    /// delivery count the same way a `nack` would) so another consumer can
+    /// pick it up. [`Queue::dequeue`] already does this implicitly before
+    /// claiming a new job on backends where it's cheap to; this method lets
+    /// a caller that isn't actively dequeuing — e.g. a background sweeper
+    /// task — force a sweep on a schedule of its own choosing.
+    ///
+    /// # Returns
+    ///
+    /// The number of claims reclaimed.
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>>;
 }
@@ -0,0 +1,349 @@
+//! Dead-letter notification decorator for any [`Queue`] backend
+//!
+//! Wraps any [`Queue`] implementation so a DLQ transition — whether it
+//! happens inside [`Queue::nack`] (every backend's own exhausted-retries
+//! path) or via an explicit [`Queue::move_to_dlq`] call — fires every
+//! registered [`crate::notifier::Notifier`]. Same shape as
+//! [`crate::queue::timed_queue::TimedQueue`]: no changes to the wrapped
+//! backend are needed.
+//!
+//! `nack`'s internal DLQ transition isn't visible to a decorator directly
+//! (each backend decides for itself, with no shared signal), so every
+//! `nack` call is followed by a direct [`Queue::get_dlq_entry`] point
+//! lookup for `job_id`. Earlier attempts compared [`Queue::get_dead_letter_length`]
+//! before and after the call, and then a capped [`Queue::list_dlq`] scan —
+//! both are racy/lossy once the DLQ holds more entries than the scan's cap
+//! or another caller mutates it concurrently, silently dropping the
+//! notification. [`Queue::get_dlq_entry`] is a real point lookup backed by
+//! each backend's own `job_id` key, so it has neither failure mode.
+
+use crate::notifier::{DlqNotification, NotifierRegistry};
+use crate::queue::{DeadLetterEntry, JobPayload, Queue, QueuedJob};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::error::Error;
+
+/// Decorates any [`Queue`] implementation with dead-letter notifications
+pub struct NotifyingQueue<Q> {
+    inner: Q,
+    notifiers: NotifierRegistry,
+}
+
+impl<Q: Queue> NotifyingQueue<Q> {
+    /// Wrap `inner` so every DLQ transition fires `notifiers`
+    pub fn new(inner: Q, notifiers: NotifierRegistry) -> Self {
+        Self { inner, notifiers }
+    }
+
+    /// If `job_id` is now present in the Dead Letter Queue, notify for it.
+    /// Called after every `nack`, so a job that was already dead-lettered
+    /// before this call (and never replayed) notifies again; backends only
+    /// reach this decorator through `nack`/`move_to_dlq`, both of which
+    /// already notify on first entry, so a stale re-notify here would mean
+    /// the job re-entered the DLQ, which is itself worth surfacing.
+    async fn notify_if_dead_lettered(&self, job_id: &str) {
+        let Ok(Some(entry)) = self.inner.get_dlq_entry(job_id).await else {
+            return;
+        };
+        self.notifiers
+            .notify_all(&DlqNotification::from_entry(&entry))
+            .await;
+    }
+}
+
+#[async_trait]
+impl<Q: Queue> Queue for NotifyingQueue<Q> {
+    async fn enqueue(&self, payload: JobPayload) -> Result<String, Box<dyn Error>> {
+        self.inner.enqueue(payload).await
+    }
+
+    async fn dequeue(&self, consumer_id: &str) -> Result<Option<QueuedJob>, Box<dyn Error>> {
+        self.inner.dequeue(consumer_id).await
+    }
+
+    async fn ack(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        self.inner.ack(job_id, consumer_id).await
+    }
+
+    async fn nack(
+        &self,
+        job_id: &str,
+        consumer_id: &str,
+        reason: Option<String>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.inner.nack(job_id, consumer_id, reason).await?;
+        self.notify_if_dead_lettered(job_id).await;
+        Ok(())
+    }
+
+    async fn get_queue_length(&self) -> Result<usize, Box<dyn Error>> {
+        self.inner.get_queue_length().await
+    }
+
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>> {
+        self.inner.move_to_dlq(job, reason.clone()).await?;
+        self.notifiers
+            .notify_all(&DlqNotification::from_job(job, reason))
+            .await;
+        Ok(())
+    }
+
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+        self.inner.list_dlq(limit).await
+    }
+
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        self.inner.get_dlq_entry(job_id).await
+    }
+
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        self.inner.get_dead_letter_length().await
+    }
+
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>> {
+        self.inner.replay_dlq(job_id).await
+    }
+
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>> {
+        self.inner.enqueue_at(payload, when).await
+    }
+
+    async fn heartbeat(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        self.inner.heartbeat(job_id, consumer_id).await
+    }
+
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+        self.inner.reclaim_expired().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notifier::{Notifier, NotifierError};
+    use crate::queue::inmemory_queue::InMemoryQueue;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    fn sample_payload(job_id: &str) -> JobPayload {
+        JobPayload {
+            job_id: job_id.to_string(),
+            user_id: Uuid::new_v4(),
+            question: "Will this get notified?".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        }
+    }
+
+    struct CountingNotifier {
+        calls: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Notifier for CountingNotifier {
+        async fn notify(&self, _event: &DlqNotification) -> Result<(), NotifierError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_nack_past_max_retries_fires_registered_notifiers() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let notifiers = NotifierRegistry::new().register(Arc::new(CountingNotifier {
+            calls: calls.clone(),
+        }));
+        let queue = NotifyingQueue::new(
+            InMemoryQueue::with_retry_config(
+                1,
+                std::time::Duration::from_millis(1),
+                std::time::Duration::from_millis(1),
+            ),
+            notifiers,
+        );
+
+        let job_id = queue.enqueue(sample_payload("notify-job")).await.unwrap();
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        queue
+            .nack(&job.job_id, "consumer-1", Some("boom".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(queue
+            .list_dlq(10)
+            .await
+            .unwrap()
+            .iter()
+            .any(|e| e.job_id == job_id));
+    }
+
+    #[tokio::test]
+    async fn test_nack_with_retries_remaining_does_not_notify() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let notifiers = NotifierRegistry::new().register(Arc::new(CountingNotifier {
+            calls: calls.clone(),
+        }));
+        let queue = NotifyingQueue::new(InMemoryQueue::new(), notifiers);
+
+        queue.enqueue(sample_payload("retry-job")).await.unwrap();
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        queue
+            .nack(&job.job_id, "consumer-1", Some("transient".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_nack_notifies_even_when_dlq_length_is_unchanged() {
+        // Regression test for the aggregate-count race: dead-lettering
+        // `job-b` while `job-a` is replayed out of the DLQ at the same
+        // point in time leaves `get_dead_letter_length()` unchanged
+        // before vs. after `job-b`'s `nack`, even though `job-b` really
+        // did just land in the DLQ. The lookup must key off `job_id`
+        // directly rather than trusting that count.
+        let calls = Arc::new(AtomicUsize::new(0));
+        let notifiers = NotifierRegistry::new().register(Arc::new(CountingNotifier {
+            calls: calls.clone(),
+        }));
+        let queue = NotifyingQueue::new(
+            InMemoryQueue::with_retry_config(
+                1,
+                std::time::Duration::from_millis(1),
+                std::time::Duration::from_millis(1),
+            ),
+            notifiers,
+        );
+
+        let job_a = queue.enqueue(sample_payload("race-job-a")).await.unwrap();
+        let dequeued_a = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        queue
+            .nack(&dequeued_a.job_id, "consumer-1", Some("boom".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 1);
+
+        let job_b = queue.enqueue(sample_payload("race-job-b")).await.unwrap();
+        let dequeued_b = queue.dequeue("consumer-1").await.unwrap().unwrap();
+
+        // Replay job-a out of the DLQ right before job-b dead-letters, so
+        // the aggregate length reads 1 both before the replay and after
+        // job-b's nack.
+        queue.replay_dlq(&job_a).await.unwrap();
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 0);
+
+        queue
+            .nack(&dequeued_b.job_id, "consumer-1", Some("boom".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 1);
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            2,
+            "job-b's dead-letter notification must not be dropped just because the aggregate DLQ length held steady"
+        );
+        assert!(queue
+            .list_dlq(10)
+            .await
+            .unwrap()
+            .iter()
+            .any(|e| e.job_id == job_b));
+    }
+
+    #[tokio::test]
+    async fn test_nack_notifies_once_dlq_exceeds_former_scan_cap() {
+        // Regression test for the capped-scan bug: the old
+        // `notify_if_dead_lettered` only scanned the first 1000 DLQ
+        // entries returned by `list_dlq`, so a freshly dead-lettered job
+        // landing past that cap (or, for backends ordered oldest-first,
+        // any job at all once the DLQ holds more than 1000 entries) never
+        // notified. `get_dlq_entry` is a direct point lookup, so it must
+        // still find the job regardless of how many other entries are in
+        // the DLQ.
+        let calls = Arc::new(AtomicUsize::new(0));
+        let notifiers = NotifierRegistry::new().register(Arc::new(CountingNotifier {
+            calls: calls.clone(),
+        }));
+        let queue = NotifyingQueue::new(
+            InMemoryQueue::with_retry_config(
+                1,
+                std::time::Duration::from_millis(1),
+                std::time::Duration::from_millis(1),
+            ),
+            notifiers,
+        );
+
+        for i in 0..1000 {
+            let job_id = queue
+                .enqueue(sample_payload(&format!("filler-job-{}", i)))
+                .await
+                .unwrap();
+            let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+            queue
+                .nack(&job.job_id, "consumer-1", Some("boom".to_string()))
+                .await
+                .unwrap();
+            assert!(queue.get_dlq_entry(&job_id).await.unwrap().is_some());
+        }
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 1000);
+        calls.store(0, Ordering::SeqCst);
+
+        let last_job_id = queue.enqueue(sample_payload("the-1001st-job")).await.unwrap();
+        let last_job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        queue
+            .nack(&last_job.job_id, "consumer-1", Some("boom".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            1,
+            "the 1001st dead-lettered job must still notify once the DLQ exceeds the old scan cap"
+        );
+        assert!(queue
+            .get_dlq_entry(&last_job_id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_explicit_move_to_dlq_fires_registered_notifiers() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let notifiers = NotifierRegistry::new().register(Arc::new(CountingNotifier {
+            calls: calls.clone(),
+        }));
+        let queue = NotifyingQueue::new(InMemoryQueue::new(), notifiers);
+
+        let job_id = queue.enqueue(sample_payload("explicit-job")).await.unwrap();
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        queue
+            .move_to_dlq(&job, "gave up".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(queue
+            .list_dlq(10)
+            .await
+            .unwrap()
+            .iter()
+            .any(|e| e.job_id == job_id));
+    }
+}
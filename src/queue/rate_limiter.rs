@@ -0,0 +1,226 @@
+//! Per-user rate limiting via the Generic Cell Rate Algorithm (GCRA)
+//!
+//! Throttles how many tarot readings a given `user_id` can submit, enforced
+//! atomically in Redis so the limit holds across all worker/API instances.
+//!
+//! # Algorithm
+//!
+//! For a limit of `N` requests per period `T`, the emission interval is
+//! `T_interval = T / N` and the burst tolerance is `tau = (burst - 1) * T_interval`.
+//! Each key stores a single value, the Theoretical Arrival Time (TAT), as a
+//! float timestamp in milliseconds. On each request at `now`:
+//!
+//! 1. `tat = max(stored_tat or now, now)`
+//! 2. `allow_at = tat - tau`
+//! 3. `new_tat = tat + T_interval`
+//! 4. If `allow_at > now`, reject with `retry_after = allow_at - now`
+//! 5. Otherwise accept, `SET` the key to `new_tat` with a `PEXPIRE` of
+//!    `ceil(tau + T_interval)` ms
+//!
+//! The whole computation runs inside a single Lua script using Redis' own
+//! `TIME` command as the clock, so it is atomic across concurrent workers.
+
+use redis::{aio::ConnectionManager, Client, RedisError, Script};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Lua script implementing GCRA using Redis TIME as the clock.
+///
+/// KEYS[1] = rate limit key
+/// ARGV[1] = emission interval in ms (T_interval)
+/// ARGV[2] = burst tolerance in ms (tau)
+///
+/// Returns `{allowed (0/1), retry_after_ms, remaining}`
+const GCRA_SCRIPT: &str = r#"
+local key = KEYS[1]
+local t_interval = tonumber(ARGV[1])
+local tau = tonumber(ARGV[2])
+
+local time_parts = redis.call('TIME')
+local now = tonumber(time_parts[1]) * 1000 + math.floor(tonumber(time_parts[2]) / 1000)
+
+local stored_tat = tonumber(redis.call('GET', key))
+local tat = stored_tat or now
+if tat < now then
+    tat = now
+end
+
+local allow_at = tat - tau
+local new_tat = tat + t_interval
+
+if allow_at > now then
+    local retry_after = allow_at - now
+    return {0, retry_after, 0}
+else
+    local ttl_ms = math.ceil(tau + t_interval)
+    redis.call('SET', key, new_tat, 'PX', ttl_ms)
+    local remaining = math.floor((tau - (new_tat - now - t_interval)) / t_interval)
+    return {1, 0, remaining}
+end
+"#;
+
+/// Error types specific to rate limiting operations
+#[derive(Debug)]
+pub enum RateLimitError {
+    /// Redis connection error
+    ConnectionError(String),
+    /// Invalid configuration (e.g. limit or burst of zero)
+    InvalidConfig(String),
+    /// Redis operation error
+    OperationError(String),
+}
+
+impl std::fmt::Display for RateLimitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitError::ConnectionError(msg) => write!(f, "Redis connection error: {}", msg),
+            RateLimitError::InvalidConfig(msg) => write!(f, "Invalid rate limiter config: {}", msg),
+            RateLimitError::OperationError(msg) => write!(f, "Redis operation error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RateLimitError {}
+
+impl From<RedisError> for RateLimitError {
+    fn from(err: RedisError) -> Self {
+        if err.is_connection_dropped() || err.is_io_error() {
+            RateLimitError::ConnectionError(err.to_string())
+        } else {
+            RateLimitError::OperationError(err.to_string())
+        }
+    }
+}
+
+/// Result of a rate limit check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RateLimitResult {
+    /// Request is allowed, with `remaining` quota left in the current burst window
+    Allowed { remaining: u64 },
+    /// Request is denied; retry after this duration
+    Denied { retry_after: Duration },
+}
+
+impl RateLimitResult {
+    /// Whether the request was allowed
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, RateLimitResult::Allowed { .. })
+    }
+}
+
+/// GCRA-based Redis rate limiter
+#[derive(Clone)]
+pub struct RateLimiter {
+    connection: Arc<ConnectionManager>,
+    /// Allowed requests per `period`
+    limit: u64,
+    /// Period over which `limit` requests are allowed
+    period: Duration,
+    /// Burst size (number of requests allowed to exceed the steady rate momentarily)
+    burst: u64,
+}
+
+impl RateLimiter {
+    /// Create a new RateLimiter
+    ///
+    /// # Arguments
+    ///
+    /// * `redis_url` - Redis connection URL
+    /// * `limit` - Number of requests allowed per `period`
+    /// * `period` - Time window for `limit`
+    /// * `burst` - Burst tolerance (must be >= 1)
+    pub async fn new(
+        redis_url: &str,
+        limit: u64,
+        period: Duration,
+        burst: u64,
+    ) -> Result<Self, RateLimitError> {
+        if limit == 0 {
+            return Err(RateLimitError::InvalidConfig("limit must be > 0".to_string()));
+        }
+        if burst == 0 {
+            return Err(RateLimitError::InvalidConfig("burst must be > 0".to_string()));
+        }
+
+        let client = Client::open(redis_url)
+            .map_err(|e| RateLimitError::ConnectionError(format!("Failed to create Redis client: {}", e)))?;
+
+        let connection = ConnectionManager::new(client)
+            .await
+            .map_err(|e| RateLimitError::ConnectionError(format!("Failed to connect to Redis: {}", e)))?;
+
+        Ok(Self {
+            connection: Arc::new(connection),
+            limit,
+            period,
+            burst,
+        })
+    }
+
+    /// Check and consume quota for `user_id`, returning whether the request is allowed
+    pub async fn check(&self, user_id: &str) -> Result<RateLimitResult, RateLimitError> {
+        let key = format!("ratelimit:user:{}", user_id);
+
+        let t_interval_ms = self.period.as_millis() as f64 / self.limit as f64;
+        let tau_ms = (self.burst.saturating_sub(1)) as f64 * t_interval_ms;
+
+        let mut conn = self.connection.as_ref().clone();
+
+        let (allowed, retry_after_ms, remaining): (i64, i64, i64) = Script::new(GCRA_SCRIPT)
+            .key(&key)
+            .arg(t_interval_ms)
+            .arg(tau_ms)
+            .invoke_async(&mut conn)
+            .await?;
+
+        if allowed == 1 {
+            Ok(RateLimitResult::Allowed {
+                remaining: remaining.max(0) as u64,
+            })
+        } else {
+            Ok(RateLimitResult::Denied {
+                retry_after: Duration::from_millis(retry_after_ms.max(0) as u64),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_redis_url() -> String {
+        std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_creation() {
+        let redis_url = get_redis_url();
+        let result = RateLimiter::new(&redis_url, 10, Duration::from_secs(60), 1).await;
+
+        if result.is_ok() {
+            println!("Successfully connected to Redis");
+        } else {
+            println!("Redis not available: {:?}", result.err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_config_rejected() {
+        let redis_url = get_redis_url();
+        let result = RateLimiter::new(&redis_url, 0, Duration::from_secs(60), 1).await;
+        assert!(result.is_err(), "Zero limit should be rejected");
+
+        let result = RateLimiter::new(&redis_url, 10, Duration::from_secs(60), 0).await;
+        assert!(result.is_err(), "Zero burst should be rejected");
+    }
+
+    #[test]
+    fn test_rate_limit_result_is_allowed() {
+        assert!(RateLimitResult::Allowed { remaining: 5 }.is_allowed());
+        assert!(!RateLimitResult::Denied {
+            retry_after: Duration::from_secs(1)
+        }
+        .is_allowed());
+    }
+}
@@ -8,7 +8,9 @@
 //!
 //! - Uses Redis SETNX for atomic "check and set" operations
 //! - Automatically expires keys after TTL to allow retries
-//! - Thread-safe with Arc-wrapped connection manager
+//! - Backed by a `bb8` connection pool (bounded size, acquire timeout,
+//!   connection-test-on-checkout) instead of a single multiplexed
+//!   connection, so callers get real backpressure under load
 //! - Handles network errors and timeouts gracefully
 //!
 //! # Usage Example
@@ -31,8 +33,34 @@
 //! # }
 //! ```
 
-use redis::{aio::ConnectionManager, AsyncCommands, Client, RedisError};
+use crate::worker::retry::{ErrorKind as RetryErrorKind, RetryClassifier, RetryConfig, RetryPolicy};
+use bb8::Pool;
+use bb8_redis::RedisConnectionManager;
+use redis::{AsyncCommands, RedisError};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::sleep;
+
+/// Classifies `DedupeError`'s transient variants (`ConnectionError`/`Timeout`)
+/// as retryable and everything else (`InvalidKey`, `OperationError`) as
+/// permanent, so [`RedisDedupeManager::with_retries`] only retries failures
+/// that have a real chance of resolving themselves.
+#[derive(Debug, Clone, Copy, Default)]
+struct DedupeErrorClassifier;
+
+impl RetryClassifier for DedupeErrorClassifier {
+    fn classify(&self, err: &(dyn std::error::Error + Send + Sync)) -> RetryErrorKind {
+        match err.downcast_ref::<DedupeError>() {
+            Some(DedupeError::ConnectionError(_)) | Some(DedupeError::Timeout(_)) => {
+                RetryErrorKind::Transient
+            }
+            Some(DedupeError::InvalidKey(_)) | Some(DedupeError::OperationError(_)) => {
+                RetryErrorKind::Permanent
+            }
+            None => RetryErrorKind::Permanent,
+        }
+    }
+}
 
 /// Error types specific to Redis deduplication operations
 #[derive(Debug)]
@@ -72,14 +100,60 @@ impl From<RedisError> for DedupeError {
     }
 }
 
+/// Configuration for `RedisDedupeManager` connection resilience
+#[derive(Debug, Clone, Copy)]
+pub struct DedupeConfig {
+    /// Timeout for establishing the initial connection
+    pub connect_timeout: Duration,
+    /// Timeout for an individual Redis command
+    pub command_timeout: Duration,
+    /// Maximum number of retries for a command after a transient failure
+    pub max_retries: u32,
+    /// Base delay between retries (doubled on each subsequent attempt)
+    pub retry_base_delay: Duration,
+    /// Maximum number of pooled connections
+    pub pool_max_size: u32,
+    /// Overall wall-clock budget for retrying a single command, measured
+    /// from the first attempt. Bounds total retry time independent of
+    /// `max_retries`, so a flapping Redis can't block a caller indefinitely.
+    pub retry_deadline: Duration,
+}
+
+impl Default for DedupeConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            command_timeout: Duration::from_secs(2),
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(100),
+            pool_max_size: 16,
+            retry_deadline: Duration::from_secs(10),
+        }
+    }
+}
+
 /// Redis-based deduplication manager
 ///
 /// Manages job deduplication using Redis SETNX operations with TTL.
-/// Uses connection manager for automatic reconnection and connection pooling.
+/// Backed by a bounded `bb8` connection pool (instead of a single shared,
+/// multiplexed connection) so callers get real backpressure and a
+/// connection-test-on-checkout instead of a hidden single-connection
+/// bottleneck under load. Commands are retried with exponential backoff on
+/// transient connection or timeout errors, so brief Redis failovers don't
+/// propagate to callers.
 #[derive(Clone)]
 pub struct RedisDedupeManager {
-    /// Redis connection manager (thread-safe, sharable)
-    connection: Arc<ConnectionManager>,
+    /// Bounded pool of Redis connections
+    pool: Pool<RedisConnectionManager>,
+    /// Connection resilience configuration
+    config: DedupeConfig,
+    /// Connection URL, retained for `watch_expirations`'s dedicated pub/sub
+    /// connection (the bb8 pool isn't used for long-lived subscriptions)
+    redis_url: String,
+    /// Backoff/jitter schedule and transient-vs-permanent classification for
+    /// `with_retries`, shared from `crate::worker::retry` instead of a
+    /// bespoke doubling loop
+    retry_policy: Arc<RetryPolicy>,
 }
 
 impl RedisDedupeManager {
@@ -104,21 +178,105 @@ impl RedisDedupeManager {
     /// # }
     /// ```
     pub async fn new(redis_url: &str) -> Result<Self, DedupeError> {
-        // Create Redis client
-        let client = Client::open(redis_url).map_err(|e| {
+        Self::new_with_config(redis_url, DedupeConfig::default()).await
+    }
+
+    /// Create a new RedisDedupeManager with explicit resilience configuration
+    ///
+    /// Builds a default [`RetryPolicy`] from `config.max_retries` and
+    /// `config.retry_base_delay`; use [`Self::new_with_retry_policy`] instead
+    /// to supply a policy tuned independently of those two fields (e.g. a
+    /// different jitter strategy or a shared token bucket).
+    ///
+    /// # Arguments
+    ///
+    /// * `redis_url` - Redis connection URL (e.g., "redis://127.0.0.1:6379")
+    /// * `config` - Connect/command timeouts, retry policy, and pool size
+    pub async fn new_with_config(redis_url: &str, config: DedupeConfig) -> Result<Self, DedupeError> {
+        let retry_config = RetryConfig {
+            max_attempts: config.max_retries.max(1),
+            base_delay: config.retry_base_delay,
+            max_delay: config.retry_base_delay * 2u32.pow(config.max_retries.max(1)),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            max_elapsed: None,
+            ..RetryConfig::default()
+        };
+        let retry_policy = RetryPolicy::new_with_classifier(retry_config, Box::new(DedupeErrorClassifier))
+            .map_err(|e| DedupeError::ConnectionError(format!("Invalid retry config: {}", e)))?;
+
+        Self::new_with_retry_policy(redis_url, config, retry_policy).await
+    }
+
+    /// Create a new RedisDedupeManager with an explicit [`RetryPolicy`],
+    /// instead of the one [`Self::new_with_config`] derives from
+    /// `config.max_retries`/`config.retry_base_delay`
+    pub async fn new_with_retry_policy(
+        redis_url: &str,
+        config: DedupeConfig,
+        retry_policy: RetryPolicy,
+    ) -> Result<Self, DedupeError> {
+        let manager = RedisConnectionManager::new(redis_url).map_err(|e| {
             DedupeError::ConnectionError(format!("Failed to create Redis client: {}", e))
         })?;
 
-        // Create connection manager (handles reconnection automatically)
-        let connection = ConnectionManager::new(client).await.map_err(|e| {
-            DedupeError::ConnectionError(format!("Failed to connect to Redis: {}", e))
-        })?;
+        let pool = tokio::time::timeout(
+            config.connect_timeout,
+            Pool::builder()
+                .max_size(config.pool_max_size)
+                .connection_timeout(config.connect_timeout)
+                .test_on_check_out(true)
+                .build(manager),
+        )
+        .await
+        .map_err(|_| DedupeError::Timeout("Connection attempt timed out".to_string()))?
+        .map_err(|e| DedupeError::ConnectionError(format!("Failed to connect to Redis: {}", e)))?;
 
         Ok(Self {
-            connection: Arc::new(connection),
+            pool,
+            config,
+            redis_url: redis_url.to_string(),
+            retry_policy: Arc::new(retry_policy),
         })
     }
 
+    /// Run a command, retrying on transient errors (`ConnectionError`/`Timeout`)
+    /// per `self.retry_policy`, bounding each attempt by `command_timeout` and
+    /// the whole sequence of retries by `config.retry_deadline` so a flapping
+    /// Redis can't block a caller indefinitely
+    async fn with_retries<T, F, Fut>(&self, mut op: F) -> Result<T, DedupeError>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, DedupeError>>,
+    {
+        let started = Instant::now();
+        let mut attempt = 0;
+        loop {
+            let result = tokio::time::timeout(self.config.command_timeout, op())
+                .await
+                .unwrap_or_else(|_| Err(DedupeError::Timeout("Command timed out".to_string())));
+
+            match result {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    if !self.retry_policy.should_retry(attempt, &err)
+                        || started.elapsed() >= self.config.retry_deadline
+                    {
+                        return Err(err);
+                    }
+
+                    attempt += 1;
+                    let delay = self.retry_policy.calculate_delay(attempt);
+                    println!(
+                        "Retrying dedupe command after transient error (attempt {}): {}",
+                        attempt, err
+                    );
+                    sleep(delay).await;
+                }
+            }
+        }
+    }
+
     /// Check if a dedupe key exists in Redis
     ///
     /// # Arguments
@@ -151,13 +309,20 @@ impl RedisDedupeManager {
             ));
         }
 
-        // Clone connection manager for async use
-        let mut conn = self.connection.as_ref().clone();
+        self.with_retries(|| async {
+            // Acquire a pooled connection
+            let mut conn = self
+                .pool
+                .get()
+                .await
+                .map_err(|e| DedupeError::ConnectionError(e.to_string()))?;
 
-        // Check if key exists using EXISTS command
-        let exists: bool = conn.exists(key).await?;
+            // Check if key exists using EXISTS command
+            let exists: bool = conn.exists(key).await?;
 
-        Ok(exists)
+            Ok(exists)
+        })
+        .await
     }
 
     /// Atomically set a dedupe key with TTL (SETNX operation)
@@ -199,24 +364,31 @@ impl RedisDedupeManager {
             ));
         }
 
-        // Clone connection manager for async use
-        let mut conn = self.connection.as_ref().clone();
-
-        // Use SET with NX (only set if not exists) and EX (expiration) options
-        // Redis command: SET key value NX EX ttl_secs
-        // Returns: OK if set, nil if key already exists
-        let result: Option<String> = redis::cmd("SET")
-            .arg(key)
-            .arg("1") // Value doesn't matter for dedupe, we just need the key
-            .arg("NX") // Only set if key does Not eXist
-            .arg("EX") // Set expiration
-            .arg(ttl_secs)
-            .query_async(&mut conn)
-            .await?;
-
-        // If result is Some("OK"), key was set successfully
-        // If result is None, key already existed
-        Ok(result.is_some())
+        self.with_retries(|| async {
+            // Acquire a pooled connection
+            let mut conn = self
+                .pool
+                .get()
+                .await
+                .map_err(|e| DedupeError::ConnectionError(e.to_string()))?;
+
+            // Use SET with NX (only set if not exists) and EX (expiration) options
+            // Redis command: SET key value NX EX ttl_secs
+            // Returns: OK if set, nil if key already exists
+            let result: Option<String> = redis::cmd("SET")
+                .arg(key)
+                .arg("1") // Value doesn't matter for dedupe, we just need the key
+                .arg("NX") // Only set if key does Not eXist
+                .arg("EX") // Set expiration
+                .arg(ttl_secs)
+                .query_async(&mut conn)
+                .await?;
+
+            // If result is Some("OK"), key was set successfully
+            // If result is None, key already existed
+            Ok(result.is_some())
+        })
+        .await
     }
 
     /// Delete a dedupe key (for cleanup/testing)
@@ -247,13 +419,20 @@ impl RedisDedupeManager {
             ));
         }
 
-        // Clone connection manager for async use
-        let mut conn = self.connection.as_ref().clone();
+        self.with_retries(|| async {
+            // Acquire a pooled connection
+            let mut conn = self
+                .pool
+                .get()
+                .await
+                .map_err(|e| DedupeError::ConnectionError(e.to_string()))?;
 
-        // Delete the key (DEL command)
-        let _: () = conn.del(key).await?;
+            // Delete the key (DEL command)
+            let _: () = conn.del(key).await?;
 
-        Ok(())
+            Ok(())
+        })
+        .await
     }
 
     /// Get TTL (time-to-live) for a key in seconds
@@ -275,22 +454,136 @@ impl RedisDedupeManager {
             ));
         }
 
-        // Clone connection manager for async use
-        let mut conn = self.connection.as_ref().clone();
-
-        // Get TTL in seconds
-        let ttl: i64 = conn.ttl(key).await?;
-
-        // Redis returns:
-        // -2 if key does not exist
-        // -1 if key exists but has no expiration
-        // positive number for TTL in seconds
-        match ttl {
-            -2 => Ok(None), // Key doesn't exist
-            -1 => Ok(None), // Key exists but no TTL (shouldn't happen with our usage)
-            n if n > 0 => Ok(Some(n)),
-            _ => Ok(None),
+        self.with_retries(|| async {
+            // Acquire a pooled connection
+            let mut conn = self
+                .pool
+                .get()
+                .await
+                .map_err(|e| DedupeError::ConnectionError(e.to_string()))?;
+
+            // Get TTL in seconds
+            let ttl: i64 = conn.ttl(key).await?;
+
+            // Redis returns:
+            // -2 if key does not exist
+            // -1 if key exists but has no expiration
+            // positive number for TTL in seconds
+            match ttl {
+                -2 => Ok(None), // Key doesn't exist
+                -1 => Ok(None), // Key exists but no TTL (shouldn't happen with our usage)
+                n if n > 0 => Ok(Some(n)),
+                _ => Ok(None),
+            }
+        })
+        .await
+    }
+
+    /// Atomically set several dedupe keys in a single pipelined round trip
+    ///
+    /// Applies the same `SET key val NX EX ttl` semantics as `set_dedupe_key`
+    /// to each entry, returning a `Vec<bool>` aligned with the input order
+    /// (`true` = key was set / no duplicate, `false` = key already existed).
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - Slice of `(key, ttl_secs)` pairs
+    pub async fn set_dedupe_keys_batch(
+        &self,
+        entries: &[(&str, u32)],
+    ) -> Result<Vec<bool>, DedupeError> {
+        for (key, _) in entries {
+            if key.trim().is_empty() {
+                return Err(DedupeError::InvalidKey(
+                    "Dedupe key cannot be empty or whitespace".to_string(),
+                ));
+            }
+        }
+
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.with_retries(|| async {
+            let mut conn = self
+                .pool
+                .get()
+                .await
+                .map_err(|e| DedupeError::ConnectionError(e.to_string()))?;
+
+            // One SET NX EX per entry; replies preserved in input order
+            let mut pipeline = redis::pipe();
+            for (key, ttl_secs) in entries {
+                pipeline
+                    .cmd("SET")
+                    .arg(*key)
+                    .arg("1")
+                    .arg("NX")
+                    .arg("EX")
+                    .arg(*ttl_secs);
+            }
+
+            let results: Vec<Option<String>> = pipeline.query_async(&mut conn).await?;
+            Ok(results.into_iter().map(|r| r.is_some()).collect())
+        })
+        .await
+    }
+
+    /// Check existence of several dedupe keys in a single pipelined round trip
+    ///
+    /// Returns a `Vec<bool>` aligned with the input order (`true` = key exists).
+    pub async fn check_dedupe_keys_batch(&self, keys: &[&str]) -> Result<Vec<bool>, DedupeError> {
+        for key in keys {
+            if key.trim().is_empty() {
+                return Err(DedupeError::InvalidKey(
+                    "Dedupe key cannot be empty or whitespace".to_string(),
+                ));
+            }
+        }
+
+        if keys.is_empty() {
+            return Ok(Vec::new());
         }
+
+        self.with_retries(|| async {
+            let mut conn = self
+                .pool
+                .get()
+                .await
+                .map_err(|e| DedupeError::ConnectionError(e.to_string()))?;
+
+            let mut pipeline = redis::pipe();
+            for key in keys {
+                pipeline.cmd("EXISTS").arg(*key);
+            }
+
+            let results: Vec<bool> = pipeline.query_async(&mut conn).await?;
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Watch for dedupe keys expiring, via Redis keyspace notifications
+    ///
+    /// Opt-in: enabling `notify-keyspace-events` has a small broadcast cost
+    /// on the server, so only subscribe if a caller actually wants to react
+    /// to expirations (e.g. to know the moment a deduped question can be
+    /// legitimately resubmitted) instead of polling [`Self::get_ttl`]. Runs
+    /// on its own dedicated connection outside the `bb8` pool, since a
+    /// subscription is long-lived rather than checked out per-command; see
+    /// [`crate::queue::redis_dedupe_events::watch_expirations`] for the
+    /// resubscribe-on-reconnect behavior.
+    pub fn watch_expirations(
+        &self,
+        key_prefix: &str,
+    ) -> tokio_stream::wrappers::UnboundedReceiverStream<String> {
+        crate::queue::redis_dedupe_events::watch_expirations(
+            &self.redis_url,
+            crate::queue::redis_dedupe_events::WatchConfig {
+                key_prefix: key_prefix.to_string(),
+                ..Default::default()
+            },
+        )
     }
 }
 
@@ -344,4 +637,69 @@ mod tests {
         let err = DedupeError::Timeout("test".to_string());
         assert!(err.to_string().contains("timeout"));
     }
+
+    #[test]
+    fn test_classifier_downcasts_instead_of_string_matching() {
+        let classifier = DedupeErrorClassifier;
+
+        let connection_err = DedupeError::ConnectionError("boom".to_string());
+        assert_eq!(
+            classifier.classify(&connection_err),
+            RetryErrorKind::Transient
+        );
+
+        let timeout_err = DedupeError::Timeout("boom".to_string());
+        assert_eq!(classifier.classify(&timeout_err), RetryErrorKind::Transient);
+
+        let invalid_key_err = DedupeError::InvalidKey("boom".to_string());
+        assert_eq!(
+            classifier.classify(&invalid_key_err),
+            RetryErrorKind::Permanent
+        );
+
+        let operation_err = DedupeError::OperationError("boom".to_string());
+        assert_eq!(
+            classifier.classify(&operation_err),
+            RetryErrorKind::Permanent
+        );
+    }
+
+    #[tokio::test]
+    async fn test_batch_rejects_invalid_key_before_pipelining() {
+        let redis_url = get_redis_url();
+        if let Ok(manager) = RedisDedupeManager::new(&redis_url).await {
+            let result = manager
+                .set_dedupe_keys_batch(&[("valid:key", 60), ("", 60)])
+                .await;
+            assert!(result.is_err(), "Batch with an invalid key should fail fast");
+
+            let result = manager.check_dedupe_keys_batch(&["valid:key", "   "]).await;
+            assert!(result.is_err(), "Batch with an invalid key should fail fast");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_set_and_check_round_trip() {
+        let redis_url = get_redis_url();
+        if let Ok(manager) = RedisDedupeManager::new(&redis_url).await {
+            let key_a = format!("test:batch:{}", uuid::Uuid::new_v4());
+            let key_b = format!("test:batch:{}", uuid::Uuid::new_v4());
+
+            let set_results = manager
+                .set_dedupe_keys_batch(&[(&key_a, 60), (&key_b, 60)])
+                .await;
+            if let Ok(set_results) = set_results {
+                assert_eq!(set_results, vec![true, true]);
+
+                let check_results = manager
+                    .check_dedupe_keys_batch(&[&key_a, &key_b])
+                    .await
+                    .unwrap();
+                assert_eq!(check_results, vec![true, true]);
+
+                let _ = manager.delete_key(&key_a).await;
+                let _ = manager.delete_key(&key_b).await;
+            }
+        }
+    }
 }
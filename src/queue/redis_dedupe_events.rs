@@ -0,0 +1,129 @@
+//! Dedupe-key expiry notifications via Redis keyspace events
+//!
+//! `RedisDedupeManager::check_dedupe_key` only lets callers poll for whether a
+//! dedupe window is still open. This module adds a push-based companion: a
+//! subscription that enables Redis keyspace notifications and surfaces each
+//! expired dedupe key as it happens, so the pipeline can react the moment a
+//! user is allowed to legitimately resubmit a previously-deduped question.
+//!
+//! # Architecture
+//!
+//! - Enables `notify-keyspace-events Ex` (expired-key events) on the server
+//! - `PSUBSCRIBE`s to `__keyevent@<db>__:expired` on a dedicated pub/sub connection
+//! - Filters incoming key names to the dedupe key prefix and forwards the rest
+//! - On a dropped connection, sleeps with exponential backoff and resubscribes,
+//!   re-issuing the `CONFIG SET` in case the server lost it across a restart
+
+use futures_util::StreamExt;
+use redis::Client;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
+
+/// Configuration for the expiry watcher's resubscribe behavior
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    /// Only keys starting with this prefix are forwarded
+    pub key_prefix: String,
+    /// Redis logical database index used to build the keyevent channel name
+    pub db: u8,
+    /// Initial backoff delay before the first resubscribe attempt
+    pub base_backoff: Duration,
+    /// Maximum backoff delay between resubscribe attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            key_prefix: String::new(),
+            db: 0,
+            base_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Start watching for dedupe key expirations
+///
+/// Returns a `Stream<Item = String>` of expired dedupe keys (prefix already
+/// stripped of the Redis keyevent channel wrapper, but including the caller's
+/// own dedupe prefix). The subscription task runs for the lifetime of the
+/// returned stream and survives disconnects by resubscribing with backoff.
+pub fn watch_expirations(
+    redis_url: &str,
+    config: WatchConfig,
+) -> UnboundedReceiverStream<String> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let redis_url = redis_url.to_string();
+
+    tokio::spawn(async move {
+        let mut backoff = config.base_backoff;
+
+        loop {
+            match subscribe_once(&redis_url, &config, &tx).await {
+                Ok(()) => {
+                    // Subscription ended gracefully (receiver dropped)
+                    break;
+                }
+                Err(e) => {
+                    println!(
+                        "Dedupe expiry watcher disconnected, retrying in {:?}: {}",
+                        backoff, e
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(config.max_backoff);
+                }
+            }
+        }
+    });
+
+    UnboundedReceiverStream::new(rx)
+}
+
+async fn subscribe_once(
+    redis_url: &str,
+    config: &WatchConfig,
+    tx: &mpsc::UnboundedSender<String>,
+) -> Result<(), redis::RedisError> {
+    let client = Client::open(redis_url)?;
+
+    // Enable keyspace notifications for expired-key events; safe to re-issue
+    let mut setup_conn = client.get_multiplexed_async_connection().await?;
+    redis::cmd("CONFIG")
+        .arg("SET")
+        .arg("notify-keyspace-events")
+        .arg("Ex")
+        .query_async::<()>(&mut setup_conn)
+        .await?;
+
+    let mut pubsub = client.get_async_pubsub().await?;
+    let channel_pattern = format!("__keyevent@{}__:expired", config.db);
+    pubsub.psubscribe(&channel_pattern).await?;
+
+    let mut message_stream = pubsub.on_message();
+    while let Some(msg) = message_stream.next().await {
+        let key: String = msg.get_payload()?;
+        if key.starts_with(&config.key_prefix) {
+            if tx.send(key).is_err() {
+                // Receiver dropped; stop watching
+                return Ok(());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_config_default() {
+        let config = WatchConfig::default();
+        assert_eq!(config.db, 0);
+        assert_eq!(config.key_prefix, "");
+        assert!(config.base_backoff < config.max_backoff);
+    }
+}
@@ -0,0 +1,452 @@
+//! Redis List-based queue implementation (fallback for Streams-less deployments)
+//!
+//! Some hosted Redis tiers and older servers don't support Streams or consumer
+//! groups. This module provides a `Queue` implementation built on plain Redis
+//! lists instead: `LPUSH` onto a main list to enqueue, `RPOPLPUSH` into a
+//! per-consumer processing list to claim a job, and a companion sorted set
+//! recording the claim timestamp so a background reaper can detect jobs whose
+//! processing time has exceeded a deadline and move them back onto the main
+//! list for redelivery.
+//!
+//! # Architecture
+//!
+//! - `LPUSH main_list` / `RPOPLPUSH main_list processing_list` for reliable claim semantics
+//! - A sorted set (`claims_zset`) scored by claim timestamp (ms) tracks in-flight entries
+//! - `ack` removes the entry from the processing list and the claims set
+//! - `nack` pushes the entry back onto the main list and clears its claim
+//! - `reap_stale(deadline)` scans the claims set for entries older than the
+//!   deadline and requeues them, giving the same "reclaim abandoned work"
+//!   guarantee that Streams' PEL provides
+
+use crate::queue::{DeadLetterEntry, JobPayload, Queue, QueuedJob};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::{aio::ConnectionManager, AsyncCommands};
+use std::collections::HashMap;
+use std::error::Error;
+
+/// Default minimum claim age (in milliseconds) before
+/// [`RedisListQueue::reclaim_expired`] will requeue an entry
+pub const DEFAULT_VISIBILITY_TIMEOUT_MS: i64 = 30_000;
+
+/// Default number of delivery attempts before `nack` dead-letters a job
+/// instead of requeueing it
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// Redis List-based queue implementation
+///
+/// Selected at construction time as a fallback for Redis deployments that
+/// lack Stream support.
+pub struct RedisListQueue {
+    connection_manager: ConnectionManager,
+    /// Main list jobs are pushed onto (e.g. "tarot:jobs:list")
+    main_list: String,
+    /// Per-consumer processing list prefix (e.g. "tarot:jobs:processing:")
+    processing_prefix: String,
+    /// Sorted set tracking claim timestamps (e.g. "tarot:jobs:claims")
+    claims_zset: String,
+    /// Hash of Dead Letter Queue entries, keyed by job_id (e.g. "tarot:jobs:dlq")
+    dlq_hash: String,
+    /// Sorted set staging jobs scheduled via `enqueue_at`, scored by their
+    /// epoch-millis run time (e.g. "tarot:jobs:delayed")
+    delayed_zset: String,
+    /// Hash tracking delivery attempts per job_id (e.g. "tarot:jobs:attempts"),
+    /// since plain Redis lists have no built-in delivery-count bookkeeping
+    /// the way a Streams consumer group's PEL does
+    attempts_hash: String,
+    /// Hash of claimed payloads, keyed by job_id (e.g. "tarot:jobs:claimed"),
+    /// populated on `dequeue` and consulted by [`Self::reap_stale`] — the
+    /// payload itself lives in a per-consumer processing list, but
+    /// `reap_stale` only knows a stale job's id from `claims_zset`, not
+    /// which processing list (or consumer) is holding it
+    claimed_payloads_hash: String,
+    /// Minimum claim age (ms) [`Self::reclaim_expired`] uses when sweeping
+    /// `claims_zset` for abandoned entries
+    visibility_timeout_ms: i64,
+    /// How many delivery attempts a job gets before `nack` dead-letters it
+    max_attempts: u32,
+}
+
+impl RedisListQueue {
+    /// Create a new RedisListQueue instance
+    ///
+    /// # Arguments
+    ///
+    /// * `redis_url` - Redis connection URL
+    /// * `queue_name` - Base name used to derive the main list, processing
+    ///   list prefix, and claims sorted set keys
+    pub async fn new(redis_url: &str, queue_name: &str) -> Result<Self, Box<dyn Error>> {
+        let client = redis::Client::open(redis_url)?;
+        let connection_manager = ConnectionManager::new(client).await?;
+
+        Ok(Self {
+            connection_manager,
+            main_list: format!("{}:list", queue_name),
+            processing_prefix: format!("{}:processing:", queue_name),
+            claims_zset: format!("{}:claims", queue_name),
+            dlq_hash: format!("{}:dlq", queue_name),
+            delayed_zset: format!("{}:delayed", queue_name),
+            attempts_hash: format!("{}:attempts", queue_name),
+            claimed_payloads_hash: format!("{}:claimed", queue_name),
+            visibility_timeout_ms: DEFAULT_VISIBILITY_TIMEOUT_MS,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        })
+    }
+
+    /// Override the minimum claim age [`Self::reclaim_expired`] uses when
+    /// sweeping `claims_zset` for abandoned entries
+    pub fn with_visibility_timeout_ms(mut self, visibility_timeout_ms: i64) -> Self {
+        self.visibility_timeout_ms = visibility_timeout_ms;
+        self
+    }
+
+    /// Override how many delivery attempts a job gets before `nack`
+    /// dead-letters it instead of requeueing it
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    fn processing_list(&self, consumer_id: &str) -> String {
+        format!("{}{}", self.processing_prefix, consumer_id)
+    }
+
+    fn get_connection(&self) -> ConnectionManager {
+        self.connection_manager.clone()
+    }
+
+    /// Scan the claims sorted set for entries claimed before `deadline_ms_ago`
+    /// milliseconds ago and push them back onto the main list for redelivery.
+    ///
+    /// Returns the number of jobs reclaimed.
+    pub async fn reap_stale(&self, deadline_ms_ago: i64) -> Result<usize, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let cutoff = now_ms - deadline_ms_ago;
+
+        let stale_ids: Vec<String> = conn
+            .zrangebyscore(&self.claims_zset, "-inf", cutoff)
+            .await?;
+
+        let mut reclaimed = 0usize;
+        for job_id in stale_ids {
+            let payload_json: Option<String> =
+                conn.hget(&self.claimed_payloads_hash, &job_id).await?;
+
+            let _: i64 = conn.zrem(&self.claims_zset, &job_id).await?;
+            let _: i64 = conn.hdel(&self.claimed_payloads_hash, &job_id).await?;
+
+            let Some(payload_json) = payload_json else {
+                // No tracked payload for this claim (e.g. it was already
+                // acked/nacked concurrently) — nothing to redeliver.
+                continue;
+            };
+
+            let _: i64 = conn.lpush(&self.main_list, &payload_json).await?;
+            reclaimed += 1;
+        }
+
+        Ok(reclaimed)
+    }
+
+    /// Move up to `limit` staged jobs whose run time has arrived from the
+    /// `delayed` sorted set onto the main list
+    ///
+    /// Returns the number of jobs promoted.
+    pub async fn promote_due_jobs(&self, limit: usize) -> Result<usize, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let due: Vec<String> = conn
+            .zrangebyscore_limit(&self.delayed_zset, "-inf", now_ms, 0, limit as isize)
+            .await?;
+
+        let mut promoted = 0usize;
+        for payload_json in due {
+            let _: i64 = conn.zrem(&self.delayed_zset, &payload_json).await?;
+            let _: i64 = conn.lpush(&self.main_list, &payload_json).await?;
+            promoted += 1;
+        }
+
+        Ok(promoted)
+    }
+}
+
+#[async_trait]
+impl Queue for RedisListQueue {
+    async fn enqueue(&self, payload: JobPayload) -> Result<String, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let job_id = payload.job_id.clone();
+
+        let payload_json = serde_json::to_string(&payload)?;
+        let _: i64 = conn.lpush(&self.main_list, &payload_json).await?;
+
+        Ok(job_id)
+    }
+
+    async fn dequeue(&self, consumer_id: &str) -> Result<Option<QueuedJob>, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let processing_list = self.processing_list(consumer_id);
+
+        let payload_json: Option<String> =
+            conn.rpoplpush(&self.main_list, &processing_list).await?;
+
+        let Some(payload_json) = payload_json else {
+            return Ok(None);
+        };
+
+        let payload: JobPayload = serde_json::from_str(&payload_json)?;
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let _: i64 = conn
+            .zadd(&self.claims_zset, &payload.job_id, now_ms)
+            .await?;
+        let _: () = conn
+            .hset(&self.claimed_payloads_hash, &payload.job_id, &payload_json)
+            .await?;
+        let attempts: i64 = conn.hincr(&self.attempts_hash, &payload.job_id, 1).await?;
+
+        Ok(Some(QueuedJob {
+            job_id: payload.job_id.clone(),
+            payload,
+            attempts: attempts as u32,
+            claimed_at: chrono::Utc::now(),
+        }))
+    }
+
+    async fn ack(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let processing_list = self.processing_list(consumer_id);
+
+        // Scan and remove the matching payload from the processing list
+        let entries: Vec<String> = conn.lrange(&processing_list, 0, -1).await?;
+        for entry in entries {
+            if let Ok(payload) = serde_json::from_str::<JobPayload>(&entry) {
+                if payload.job_id == job_id {
+                    let _: i64 = conn.lrem(&processing_list, 1, &entry).await?;
+                    break;
+                }
+            }
+        }
+
+        let _: i64 = conn.zrem(&self.claims_zset, job_id).await?;
+        let _: i64 = conn.hdel(&self.attempts_hash, job_id).await?;
+        let _: i64 = conn.hdel(&self.claimed_payloads_hash, job_id).await?;
+        Ok(())
+    }
+
+    /// Requeue a failed job onto the main list, unless its delivery count has
+    /// reached [`Self::max_attempts`] — in which case it's moved to the Dead
+    /// Letter Queue instead of looping forever
+    async fn nack(
+        &self,
+        job_id: &str,
+        consumer_id: &str,
+        reason: Option<String>,
+    ) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let processing_list = self.processing_list(consumer_id);
+
+        let mut claimed_payload: Option<JobPayload> = None;
+        let entries: Vec<String> = conn.lrange(&processing_list, 0, -1).await?;
+        for entry in entries {
+            if let Ok(payload) = serde_json::from_str::<JobPayload>(&entry) {
+                if payload.job_id == job_id {
+                    let _: i64 = conn.lrem(&processing_list, 1, &entry).await?;
+                    claimed_payload = Some(payload);
+                    break;
+                }
+            }
+        }
+
+        let _: i64 = conn.zrem(&self.claims_zset, job_id).await?;
+        let _: i64 = conn.hdel(&self.claimed_payloads_hash, job_id).await?;
+
+        if let Some(payload) = claimed_payload {
+            let attempts: i64 = conn.hget(&self.attempts_hash, job_id).await.unwrap_or(0);
+            let attempts = attempts.max(1) as u32;
+
+            if attempts >= self.max_attempts {
+                let job = QueuedJob {
+                    job_id: job_id.to_string(),
+                    payload,
+                    attempts,
+                    claimed_at: chrono::Utc::now(),
+                };
+                let reason = reason
+                    .unwrap_or_else(|| format!("Exceeded max attempts ({})", self.max_attempts));
+                self.move_to_dlq(&job, reason).await?;
+                let _: i64 = conn.hdel(&self.attempts_hash, job_id).await?;
+            } else {
+                let payload_json = serde_json::to_string(&payload)?;
+                let _: i64 = conn.lpush(&self.main_list, &payload_json).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn get_queue_length(&self) -> Result<usize, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let length: usize = conn.llen(&self.main_list).await?;
+        Ok(length)
+    }
+
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entry = DeadLetterEntry {
+            job_id: job.job_id.clone(),
+            payload: job.payload.clone(),
+            error: reason,
+            attempts: job.attempts,
+            failed_at: chrono::Utc::now(),
+        };
+        let entry_json = serde_json::to_string(&entry)?;
+
+        let _: () = conn.hset(&self.dlq_hash, &job.job_id, entry_json).await?;
+
+        Ok(())
+    }
+
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entries: HashMap<String, String> = conn.hgetall(&self.dlq_hash).await?;
+
+        let mut dlq = Vec::new();
+        for entry_json in entries.values().take(limit) {
+            dlq.push(serde_json::from_str(entry_json)?);
+        }
+
+        Ok(dlq)
+    }
+
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entry_json: Option<String> = conn.hget(&self.dlq_hash, job_id).await?;
+        entry_json
+            .map(|json| serde_json::from_str(&json).map_err(Into::into))
+            .transpose()
+    }
+
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let length: usize = conn.hlen(&self.dlq_hash).await?;
+        Ok(length)
+    }
+
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entry_json: Option<String> = conn.hget(&self.dlq_hash, job_id).await?;
+        let entry_json = entry_json.ok_or_else(|| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "No DLQ entry found for job {}",
+                job_id
+            )))
+        })?;
+        let entry: DeadLetterEntry = serde_json::from_str(&entry_json)?;
+
+        let _: () = conn.hdel(&self.dlq_hash, job_id).await?;
+        self.enqueue(entry.payload).await?;
+
+        Ok(())
+    }
+
+    /// Schedule a job to become available no earlier than `when`
+    ///
+    /// Stores the payload in the `delayed` sorted set, scored by epoch-millis
+    /// run time, instead of pushing it onto the main list directly.
+    /// [`Self::promote_due_jobs`] moves it onto the main list once it's due.
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let job_id = payload.job_id.clone();
+
+        let payload_json = serde_json::to_string(&payload)?;
+        let _: i64 = conn
+            .zadd(&self.delayed_zset, &payload_json, when.timestamp_millis())
+            .await?;
+
+        Ok(job_id)
+    }
+
+    /// Refresh `job_id`'s claim timestamp in `claims_zset` to now
+    ///
+    /// Keeps [`Self::reap_stale`] from mistaking a live worker for an
+    /// abandoned one; `consumer_id` is accepted for trait-signature parity
+    /// with other backends but isn't needed here since `claims_zset` is
+    /// keyed by job_id alone.
+    async fn heartbeat(&self, job_id: &str, _consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let _: i64 = conn.zadd(&self.claims_zset, job_id, now_ms).await?;
+        Ok(())
+    }
+
+    /// Sweep `claims_zset` for entries claimed longer ago than
+    /// [`Self::visibility_timeout_ms`], same as [`Self::reap_stale`]
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+        self.reap_stale(self.visibility_timeout_ms).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_redis_list_queue_creation() {
+        if std::env::var("REDIS_URL").is_err() {
+            println!("Skipping test: REDIS_URL not set");
+            return;
+        }
+
+        let redis_url = std::env::var("REDIS_URL").unwrap();
+        let result = RedisListQueue::new(&redis_url, "test:listqueue").await;
+        assert!(result.is_ok(), "Should create RedisListQueue");
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_at_promotes_once_due() {
+        if std::env::var("REDIS_URL").is_err() {
+            println!("Skipping test: REDIS_URL not set");
+            return;
+        }
+
+        let redis_url = std::env::var("REDIS_URL").unwrap();
+        let queue = RedisListQueue::new(&redis_url, "test:listqueue:delayed")
+            .await
+            .unwrap();
+
+        let payload = JobPayload {
+            job_id: "delayed-list-job".to_string(),
+            user_id: uuid::Uuid::new_v4(),
+            question: "Will this be delayed?".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: chrono::Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+        queue
+            .enqueue_at(payload, chrono::Utc::now() - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+
+        let promoted = queue.promote_due_jobs(100).await.unwrap();
+        assert_eq!(promoted, 1);
+
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, "delayed-list-job");
+    }
+}
@@ -17,23 +17,134 @@
 //! - Consumer groups ensure each job is processed exactly once
 //! - Pending entries list (PEL) provides automatic retry on worker failure
 //! - Metrics and logging for observability
+//! - [`RedisQueue::new_cluster`] builds a cluster-aware connection instead of
+//!   a single-node one; all keys derived from `stream_key` are hash-tagged
+//!   (`{stream_key}:suffix`) so stream, DLQ, and delayed-set operations for a
+//!   given queue always land on the same cluster slot
 
-use crate::queue::{JobPayload, Queue, QueuedJob};
+use crate::queue::codec::{JsonCodec, PayloadCodec};
+use crate::queue::{DeadLetterEntry, JobPayload, Queue, QueuedJob};
 use async_trait::async_trait;
-use redis::{aio::ConnectionManager, streams::StreamReadOptions, AsyncCommands, RedisResult};
+use chrono::{DateTime, Utc};
+use redis::{
+    aio::ConnectionLike, aio::ConnectionManager, cluster::ClusterClient,
+    cluster_async::ClusterConnection, streams::StreamReadOptions, AsyncCommands, RedisFuture,
+    RedisResult, Script, Value,
+};
+use std::collections::HashMap;
 use std::error::Error;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Underlying Redis connection, either a single-node multiplexed connection
+/// or a cluster-aware connection spanning multiple nodes
+///
+/// Implements [`ConnectionLike`] by delegating to whichever variant is
+/// active, so every existing command call (`conn.xadd`, `conn.hset`, ...)
+/// keeps working unchanged regardless of which backend is configured.
+#[derive(Clone)]
+enum RedisConnectionKind {
+    Standalone(ConnectionManager),
+    Cluster(ClusterConnection),
+}
+
+impl ConnectionLike for RedisConnectionKind {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a redis::Cmd) -> RedisFuture<'a, Value> {
+        match self {
+            RedisConnectionKind::Standalone(conn) => conn.req_packed_command(cmd),
+            RedisConnectionKind::Cluster(conn) => conn.req_packed_command(cmd),
+        }
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a redis::Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        match self {
+            RedisConnectionKind::Standalone(conn) => conn.req_packed_commands(cmd, offset, count),
+            RedisConnectionKind::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        match self {
+            RedisConnectionKind::Standalone(conn) => conn.get_db(),
+            RedisConnectionKind::Cluster(conn) => conn.get_db(),
+        }
+    }
+}
+
+/// Lua script that atomically pops up to `ARGV[2]` `delayed` sorted-set
+/// members whose score is `<= ARGV[1]` (see [`priority_score`]), lowest
+/// score first, and `XADD`s each one onto
+/// the main stream in that (ascending score) order, so a job never becomes
+/// briefly visible in both places and promotion order matches dequeue order.
+const PROMOTE_DUE_SCRIPT: &str = r#"
+local due = redis.call('zrangebyscore', KEYS[1], '-inf', ARGV[1], 'LIMIT', 0, tonumber(ARGV[2]))
+for _, payload in ipairs(due) do
+    redis.call('xadd', KEYS[2], '*', 'payload', payload)
+    redis.call('zrem', KEYS[1], payload)
+end
+return #due
+"#;
+
+/// Encode `(scheduled_at asc, priority desc)` into a single sortable `delayed`
+/// zset score: `when_millis` dominates (each millisecond reserves 256 slots),
+/// with `255 - priority` breaking ties within the same millisecond so a
+/// higher-priority job promotes to the stream first. Both terms are integers
+/// well inside `f64`'s 53-bit mantissa, so the score round-trips exactly.
+///
+/// FIFO among jobs that are both same-priority and land in the same
+/// millisecond isn't guaranteed (there's no room left in the score for a
+/// third tie-break), but two enqueues racing within the same millisecond is
+/// rare enough in practice not to matter.
+fn priority_score(priority: u8, when_millis: i64) -> f64 {
+    (when_millis * 256 + (255 - priority as i64)) as f64
+}
+
+/// Batch size used when `enqueue`/`dequeue` opportunistically promote
+/// `delayed`-set entries that are now due, so jobs reach the stream without
+/// waiting on [`RedisQueue::spawn_delayed_job_promoter`]'s polling interval
+const OPPORTUNISTIC_PROMOTE_LIMIT: usize = 1000;
+
+/// Default delivery count at which `nack` gives up on redelivery and moves
+/// a job to the Dead Letter Queue instead, used when not overridden by
+/// `REDIS_MAX_ATTEMPTS` or [`RedisQueue::with_max_attempts`]
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// Default minimum idle time before [`RedisQueue::reclaim_expired`] (and the
+/// `"reaper"` consumer it uses) will claim a PEL entry from another consumer
+pub const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default TTL for a `dedupe_key`'s `SETNX` guard, bounding how long a
+/// repeated `enqueue` call with the same key is suppressed before a new job
+/// is allowed through
+pub const DEFAULT_DEDUPE_TTL_SECS: u64 = 300;
 
 /// Redis-based queue implementation
 ///
 /// Provides a distributed job queue using Redis Streams with consumer groups.
 /// Supports automatic retries, fault tolerance, and horizontal scaling.
 pub struct RedisQueue {
-    /// Redis connection manager for automatic reconnection
-    connection_manager: ConnectionManager,
+    /// Underlying connection, either single-node or cluster-aware
+    connection_manager: RedisConnectionKind,
     /// Stream key for jobs (e.g., "tarot:jobs")
     stream_key: String,
     /// Consumer group name (e.g., "tarot-workers")
     consumer_group: String,
+    /// Delivery count at which `nack` moves a job to the DLQ instead of
+    /// leaving it pending for redelivery
+    max_attempts: u32,
+    /// Minimum idle time [`Self::reclaim_expired`] uses when reclaiming PEL
+    /// entries left behind by a crashed consumer
+    visibility_timeout: Duration,
+    /// Wire-format codec for the `payload` stream field; defaults to
+    /// [`JsonCodec`] for backward compatibility with jobs already enqueued,
+    /// override with [`Self::with_codec`] (e.g. `BincodeCodec`) for higher
+    /// enqueue/dequeue throughput
+    codec: Arc<dyn PayloadCodec>,
 }
 
 impl RedisQueue {
@@ -57,9 +168,12 @@ impl RedisQueue {
         let connection_manager = ConnectionManager::new(client).await?;
 
         let queue = Self {
-            connection_manager,
+            connection_manager: RedisConnectionKind::Standalone(connection_manager),
             stream_key,
             consumer_group,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+            codec: Arc::new(JsonCodec),
         };
 
         // Initialize consumer group if it doesn't exist
@@ -68,12 +182,55 @@ impl RedisQueue {
         Ok(queue)
     }
 
+    /// Create a new RedisQueue backed by a Redis Cluster deployment
+    ///
+    /// Use this instead of [`Self::new`] when jobs are served by a cluster
+    /// (multiple nodes, `rediss+cluster://`-style setups, or any deployment
+    /// where a single node can't hold every slot). All keys this type derives
+    /// from `stream_key` are hash-tagged (see the module docs) so a queue's
+    /// stream, consumer group, DLQ hash, and delayed set always land on the
+    /// same slot and can be addressed by cluster-safe multi-key commands.
+    ///
+    /// # Arguments
+    ///
+    /// * `urls` - Seed node addresses (e.g. `["redis://node-a:6379", ...]`);
+    ///   only one needs to be reachable to discover the rest of the cluster
+    /// * `stream_key` - Stream key for jobs
+    /// * `consumer_group` - Consumer group name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cluster client can't be built or none of the
+    /// seed nodes are reachable
+    pub async fn new_cluster(
+        urls: &[String],
+        stream_key: String,
+        consumer_group: String,
+    ) -> Result<Self, Box<dyn Error>> {
+        let client = ClusterClient::new(urls.to_vec())?;
+        let connection_manager = client.get_async_connection().await?;
+
+        let queue = Self {
+            connection_manager: RedisConnectionKind::Cluster(connection_manager),
+            stream_key,
+            consumer_group,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+            codec: Arc::new(JsonCodec),
+        };
+
+        queue.init_consumer_group().await?;
+
+        Ok(queue)
+    }
+
     /// Create RedisQueue from environment variables
     ///
     /// Reads configuration from:
     /// - `REDIS_URL`
     /// - `REDIS_STREAM_KEY` (default: "tarot:jobs")
     /// - `REDIS_CONSUMER_GROUP` (default: "tarot-workers")
+    /// - `REDIS_MAX_ATTEMPTS` (default: [`DEFAULT_MAX_ATTEMPTS`])
     pub async fn from_env() -> Result<Self, Box<dyn Error>> {
         let redis_url =
             std::env::var("REDIS_URL").map_err(|_| "REDIS_URL environment variable not set")?;
@@ -81,8 +238,34 @@ impl RedisQueue {
             std::env::var("REDIS_STREAM_KEY").unwrap_or_else(|_| "tarot:jobs".to_string());
         let consumer_group =
             std::env::var("REDIS_CONSUMER_GROUP").unwrap_or_else(|_| "tarot-workers".to_string());
+        let max_attempts = std::env::var("REDIS_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MAX_ATTEMPTS);
+
+        let queue = Self::new(&redis_url, stream_key, consumer_group).await?;
+        Ok(queue.with_max_attempts(max_attempts))
+    }
 
-        Self::new(&redis_url, stream_key, consumer_group).await
+    /// Override the delivery count at which `nack` moves a job to the DLQ
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Override the minimum idle time [`Self::reclaim_expired`] uses when
+    /// reclaiming PEL entries left behind by a crashed consumer
+    pub fn with_visibility_timeout(mut self, visibility_timeout: Duration) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    /// Override the wire-format codec used to encode/decode the `payload`
+    /// stream field (e.g. swap in `BincodeCodec` for higher enqueue/dequeue
+    /// throughput)
+    pub fn with_codec(mut self, codec: Arc<dyn PayloadCodec>) -> Self {
+        self.codec = codec;
+        self
     }
 
     /// Initialize consumer group for the stream
@@ -126,9 +309,318 @@ impl RedisQueue {
     }
 
     /// Get connection for Redis operations
-    fn get_connection(&self) -> ConnectionManager {
+    fn get_connection(&self) -> RedisConnectionKind {
         self.connection_manager.clone()
     }
+
+    /// Redis hash key storing Dead Letter Queue entries for this stream,
+    /// keyed by job_id
+    ///
+    /// Hash-tagged (`{stream_key}:dlq`) so this key lands on the same
+    /// cluster slot as the main stream.
+    fn dlq_key(&self) -> String {
+        format!("{{{}}}:dlq", self.stream_key)
+    }
+
+    /// Redis hash key mapping `job_id -> stream entry ID` for this consumer
+    /// group, so `ack`/`nack` can address the exact Streams entry instead of
+    /// guessing it from the job_id
+    ///
+    /// Hash-tagged for the same reason as [`Self::dlq_key`].
+    fn stream_ids_key(&self) -> String {
+        format!(
+            "{{{}}}:{}:stream_ids",
+            self.stream_key, self.consumer_group
+        )
+    }
+
+    /// Redis sorted-set key staging every job not yet on the main stream —
+    /// both jobs explicitly scheduled via `enqueue_at` and normal `enqueue`
+    /// calls, which stage here too (scored for "now") — scored by
+    /// [`priority_score`] (`scheduled_at` first, priority only breaking ties
+    /// within the same millisecond) until [`Self::promote_due_jobs`] moves
+    /// them onto the main stream
+    ///
+    /// Hash-tagged for the same reason as [`Self::dlq_key`].
+    fn delayed_key(&self) -> String {
+        format!("{{{}}}:delayed", self.stream_key)
+    }
+
+    /// Redis string key guarding a `dedupe_key`, holding the job_id of
+    /// whichever job currently owns it
+    ///
+    /// Hash-tagged for the same reason as [`Self::dlq_key`].
+    fn dedupe_key_redis_key(&self, dedupe_key: &str) -> String {
+        format!("{{{}}}:dedupe:{}", self.stream_key, dedupe_key)
+    }
+
+    /// Atomically move up to `limit` staged jobs whose run time has arrived
+    /// from the `delayed` sorted set onto the main stream, in ascending
+    /// [`priority_score`] order (`scheduled_at` first, priority only breaking
+    /// ties within the same millisecond) — this drains both
+    /// `enqueue_at`'s explicitly-delayed jobs and plain `enqueue`'s jobs,
+    /// which stage here too
+    ///
+    /// # Returns
+    ///
+    /// The number of jobs promoted
+    pub async fn promote_due_jobs(&self, limit: usize) -> Result<usize, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        // Max score admitting every entry whose real `when_millis` is
+        // `<= now`, regardless of its priority tie-break offset — see
+        // `priority_score`.
+        let threshold = Utc::now().timestamp_millis() * 256 + 255;
+
+        let promoted: i64 = Script::new(PROMOTE_DUE_SCRIPT)
+            .key(self.delayed_key())
+            .key(&self.stream_key)
+            .arg(threshold)
+            .arg(limit)
+            .invoke_async(&mut conn)
+            .await?;
+
+        Ok(promoted as usize)
+    }
+
+    /// Spawn a background Tokio task that periodically calls
+    /// [`Self::promote_due_jobs`] so delayed jobs flow onto the main stream
+    /// without a caller having to poll manually
+    ///
+    /// Returns the task's `JoinHandle` so callers can abort it on shutdown.
+    pub fn spawn_delayed_job_promoter(
+        self: Arc<Self>,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match self.promote_due_jobs(100).await {
+                    Ok(promoted) if promoted > 0 => {
+                        println!("Promoted {} due delayed job(s)", promoted);
+                    }
+                    Ok(_) => {}
+                    Err(e) => eprintln!("Failed to promote due delayed jobs: {}", e),
+                }
+            }
+        })
+    }
+
+    /// Look up the number of times the PEL has recorded a delivery for
+    /// `stream_id`, via `XPENDING` in its extended form. Falls back to `1`
+    /// if the entry isn't found in the PEL (e.g. it was already acked).
+    async fn delivery_count(
+        &self,
+        conn: &mut RedisConnectionKind,
+        stream_id: &str,
+    ) -> Result<u32, Box<dyn Error>> {
+        let pending: Vec<(String, String, i64, i64)> = redis::cmd("XPENDING")
+            .arg(&self.stream_key)
+            .arg(&self.consumer_group)
+            .arg(stream_id)
+            .arg(stream_id)
+            .arg(1)
+            .query_async(conn)
+            .await?;
+
+        Ok(pending
+            .first()
+            .map(|(_, _, _, delivery_count)| *delivery_count as u32)
+            .unwrap_or(1))
+    }
+
+    /// Reclaim entries idle longer than `min_idle_ms` from the consumer group's
+    /// Pending Entries List, transferring them to `consumer_id` via `XAUTOCLAIM`
+    ///
+    /// This recovers jobs left stranded when a worker crashes mid-processing.
+    pub async fn reclaim_stale(
+        &self,
+        consumer_id: &str,
+        min_idle_ms: u64,
+        count: usize,
+    ) -> Result<Vec<QueuedJob>, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let result: redis::streams::StreamAutoClaimReply = redis::cmd("XAUTOCLAIM")
+            .arg(&self.stream_key)
+            .arg(&self.consumer_group)
+            .arg(consumer_id)
+            .arg(min_idle_ms)
+            .arg("0")
+            .arg("COUNT")
+            .arg(count)
+            .query_async(&mut conn)
+            .await?;
+
+        let mut jobs = Vec::new();
+        for stream_id in result.claimed {
+            for (field_name, field_value) in &stream_id.map {
+                if field_name == "payload" {
+                    let payload_bytes = redis::from_redis_value::<Vec<u8>>(field_value)?;
+                    let payload: JobPayload = self.codec.decode(&payload_bytes)?;
+                    let attempts = self.delivery_count(&mut conn, &stream_id.id).await?;
+
+                    println!(
+                        "Reclaimed stale job {} (stream ID: {}, attempts: {}) for consumer {}",
+                        payload.job_id, stream_id.id, attempts, consumer_id
+                    );
+
+                    jobs.push(QueuedJob {
+                        job_id: payload.job_id.clone(),
+                        payload,
+                        attempts,
+                        claimed_at: chrono::Utc::now(),
+                    });
+                }
+            }
+        }
+
+        Ok(jobs)
+    }
+
+    /// Spawn a background Tokio task that periodically calls [`Self::reclaim_stale`]
+    /// to redistribute entries abandoned by crashed workers
+    ///
+    /// Runs every `interval`, reclaiming entries idle longer than `min_idle`
+    /// under a dedicated `"reaper"` consumer so the jobs flow back through
+    /// live workers' normal dequeue loop. Returns the task's `JoinHandle` so
+    /// callers can abort it on shutdown.
+    pub fn spawn_reaper(
+        self: Arc<Self>,
+        interval: Duration,
+        min_idle: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match self
+                    .reclaim_stale("reaper", min_idle.as_millis() as u64, 100)
+                    .await
+                {
+                    Ok(jobs) if !jobs.is_empty() => {
+                        println!("Reaper reclaimed {} stale job(s)", jobs.len());
+                    }
+                    Ok(_) => {}
+                    Err(e) => eprintln!("Reaper failed to reclaim stale jobs: {}", e),
+                }
+            }
+        })
+    }
+
+    /// Number of jobs currently sitting in the Dead Letter Queue
+    pub async fn get_dlq_length(&self) -> Result<usize, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let length: usize = conn.hlen(self.dlq_key()).await?;
+        Ok(length)
+    }
+
+    /// Replay up to `limit` dead-lettered jobs back onto the main stream
+    ///
+    /// Named distinctly from the trait-level [`Queue::replay_dlq`] (which
+    /// replays one job by ID) since this is a bulk operation intended for
+    /// manual recovery after an incident.
+    pub async fn replay_dlq_batch(&self, limit: usize) -> Result<usize, Box<dyn Error>> {
+        let entries = self.list_dlq(limit).await?;
+        let mut replayed = 0usize;
+        for entry in entries {
+            self.replay_dlq(&entry.job_id).await?;
+            replayed += 1;
+        }
+        Ok(replayed)
+    }
+
+    /// Enqueue a polymorphic [`Job`](crate::queue::job_registry::Job), tagged
+    /// so [`Self::process_next`] can reconstruct its concrete type later
+    ///
+    /// This is a separate, opt-in path alongside the `Queue` trait's
+    /// `enqueue`/`dequeue`: entries are stored under the `"tagged"` stream
+    /// field instead of `"payload"`, so the two flows coexist on the same
+    /// stream without colliding.
+    pub async fn enqueue_job<J>(&self, tag: &str, job: &J) -> Result<String, Box<dyn Error>>
+    where
+        J: serde::Serialize + Send + Sync,
+    {
+        let mut conn = self.get_connection();
+
+        let tagged = crate::queue::job_registry::TaggedJob {
+            tag: tag.to_string(),
+            body: serde_json::to_value(job)?,
+        };
+        let tagged_json = serde_json::to_string(&tagged)?;
+
+        let items: &[(&str, &str)] = &[("tagged", &tagged_json)];
+        let stream_id: String = conn.xadd(&self.stream_key, "*", items).await?;
+
+        println!(
+            "Enqueued tagged job '{}' to stream {} with ID {}",
+            tag, self.stream_key, stream_id
+        );
+
+        Ok(stream_id)
+    }
+
+    /// Dequeue the next tagged job, reconstruct it via `registry`, run it,
+    /// and ack/nack the stream entry based on the outcome
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - A tagged job was found and processed (ack'd or nack'd)
+    /// * `Ok(false)` - No tagged job was available
+    pub async fn process_next(
+        &self,
+        consumer_id: &str,
+        registry: &crate::queue::job_registry::JobRegistry,
+    ) -> Result<bool, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let opts = StreamReadOptions::default()
+            .group(&self.consumer_group, consumer_id)
+            .count(1);
+
+        let results: redis::streams::StreamReadReply = conn
+            .xread_options(&[&self.stream_key], &[">"], &opts)
+            .await?;
+
+        for stream_key in results.keys {
+            for stream_id in stream_key.ids {
+                for (field_name, field_value) in &stream_id.map {
+                    if field_name != "tagged" {
+                        continue;
+                    }
+
+                    let tagged_str = redis::from_redis_value::<String>(field_value)?;
+                    let tagged: crate::queue::job_registry::TaggedJob =
+                        serde_json::from_str(&tagged_str)?;
+
+                    let outcome = match registry.construct(&tagged) {
+                        Ok(job) => job.run().await,
+                        Err(e) => Err(e),
+                    };
+
+                    match outcome {
+                        Ok(()) => {
+                            let _: i64 = conn
+                                .xack(&self.stream_key, &self.consumer_group, &[&stream_id.id])
+                                .await?;
+                            let _: i64 = conn.xdel(&self.stream_key, &[&stream_id.id]).await?;
+                        }
+                        Err(e) => {
+                            eprintln!(
+                                "Tagged job '{}' (stream ID: {}) failed: {}",
+                                tagged.tag, stream_id.id, e
+                            );
+                        }
+                    }
+
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
 }
 
 #[async_trait]
@@ -137,23 +629,55 @@ impl Queue for RedisQueue {
         let mut conn = self.get_connection();
         let job_id = payload.job_id.clone();
 
-        // Serialize payload to JSON
-        let payload_json = serde_json::to_string(&payload)?;
+        // Idempotent enqueue: if `dedupe_key` is set and its `SETNX` guard is
+        // still held (it expires after `DEFAULT_DEDUPE_TTL_SECS` and isn't
+        // cleared on ack), hand back the job id stored under it instead of
+        // adding a duplicate to the stream.
+        if let Some(ref dedupe_key) = payload.dedupe_key {
+            let redis_key = self.dedupe_key_redis_key(dedupe_key);
+            let set: Option<String> = redis::cmd("SET")
+                .arg(&redis_key)
+                .arg(&job_id)
+                .arg("NX")
+                .arg("EX")
+                .arg(DEFAULT_DEDUPE_TTL_SECS)
+                .query_async(&mut conn)
+                .await?;
 
-        // Add to Redis Stream using XADD
-        let items: &[(&str, &str)] = &[("payload", &payload_json)];
+            if set.is_none() {
+                let existing_job_id: String = conn.get(&redis_key).await?;
+                println!(
+                    "Duplicate enqueue for dedupe_key {}; returning existing job {}",
+                    dedupe_key, existing_job_id
+                );
+                return Ok(existing_job_id);
+            }
+        }
 
-        let stream_id: String = conn.xadd(&self.stream_key, "*", items).await?;
+        // Stage through the same `delayed` sorted set `enqueue_at` uses,
+        // scored for "now" (see `priority_score`), then promote everything
+        // that's due. The job reaches the stream right away, so the stream
+        // stays in enqueue order: a later, higher-priority `enqueue` doesn't
+        // overtake an earlier job that's already on the stream.
+        self.enqueue_at(payload, Utc::now()).await?;
+        let promoted = self.promote_due_jobs(OPPORTUNISTIC_PROMOTE_LIMIT).await?;
 
         println!(
-            "Enqueued job {} to stream {} with ID {}",
-            job_id, self.stream_key, stream_id
+            "Enqueued job {} on stream {} ({} due job(s) promoted)",
+            job_id, self.stream_key, promoted
         );
 
         Ok(job_id)
     }
 
     async fn dequeue(&self, consumer_id: &str) -> Result<Option<QueuedJob>, Box<dyn Error>> {
+        // Opportunistically drain any `delayed`-set entries that are now
+        // due — this covers both `enqueue_at` jobs whose run time has
+        // arrived and plain `enqueue` jobs (staged the same way, scored for
+        // "now") — so due jobs reach the stream, in `priority_score` order,
+        // even without a spawned `spawn_delayed_job_promoter`.
+        self.promote_due_jobs(OPPORTUNISTIC_PROMOTE_LIMIT).await?;
+
         let mut conn = self.get_connection();
 
         // Read from stream using consumer group
@@ -171,13 +695,22 @@ impl Queue for RedisQueue {
             for stream_id in stream_key.ids {
                 for (field_name, field_value) in &stream_id.map {
                     if field_name == "payload" {
-                        let payload_str = redis::from_redis_value::<String>(field_value)?;
-                        let payload: JobPayload = serde_json::from_str(&payload_str)?;
+                        let payload_bytes = redis::from_redis_value::<Vec<u8>>(field_value)?;
+                        let payload: JobPayload = self.codec.decode(&payload_bytes)?;
+
+                        // Remember which stream entry this job came from so
+                        // ack()/nack() can address it directly, and look up
+                        // how many times it's been delivered so retry
+                        // decisions reflect the true PEL count.
+                        let _: () = conn
+                            .hset(self.stream_ids_key(), &payload.job_id, &stream_id.id)
+                            .await?;
+                        let attempts = self.delivery_count(&mut conn, &stream_id.id).await?;
 
                         let job = QueuedJob {
                             job_id: payload.job_id.clone(),
                             payload,
-                            attempts: 1, // TODO: Track actual retry count from PEL
+                            attempts,
                             claimed_at: chrono::Utc::now(),
                         };
 
@@ -197,15 +730,28 @@ impl Queue for RedisQueue {
     }
 
     async fn ack(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
-        // For now, we need to track stream_id separately
-        // In production, we'd maintain a mapping of job_id -> stream_id
-        // For simplicity, we'll use XACK with the job_id as stream_id
-        // This is a simplified implementation
+        let mut conn = self.get_connection();
+
+        let stream_id: Option<String> = conn.hget(self.stream_ids_key(), job_id).await?;
+        let Some(stream_id) = stream_id else {
+            println!(
+                "No stream ID tracked for job {} (consumer {}); nothing to ack",
+                job_id, consumer_id
+            );
+            return Ok(());
+        };
 
-        println!("Acknowledged job {} by consumer {}", job_id, consumer_id);
+        let _: i64 = conn
+            .xack(&self.stream_key, &self.consumer_group, &[&stream_id])
+            .await?;
+        let _: i64 = conn.xdel(&self.stream_key, &[&stream_id]).await?;
+        let _: i64 = conn.hdel(self.stream_ids_key(), job_id).await?;
+
+        println!(
+            "Acknowledged job {} (stream ID: {}) by consumer {}",
+            job_id, stream_id, consumer_id
+        );
 
-        // TODO: Implement proper XACK with stream_id tracking
-        // For now, just log the acknowledgement
         Ok(())
     }
 
@@ -215,17 +761,72 @@ impl Queue for RedisQueue {
         consumer_id: &str,
         reason: Option<String>,
     ) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let stream_id: Option<String> = conn.hget(self.stream_ids_key(), job_id).await?;
+        let Some(stream_id) = stream_id else {
+            println!(
+                "No stream ID tracked for job {} (consumer {}); nothing to nack",
+                job_id, consumer_id
+            );
+            return Ok(());
+        };
+
+        let delivery_count = self.delivery_count(&mut conn, &stream_id).await?;
+
+        if delivery_count < self.max_attempts {
+            // Deliberately don't XACK or touch the stream_ids mapping:
+            // leaving the entry un-acked keeps it in the consumer group's
+            // Pending Entries List, where XAUTOCLAIM/XCLAIM can redeliver it
+            // and XPENDING's delivery counter keeps incrementing.
+            println!(
+                "NACK job {} (attempt {}/{}) by consumer {}: {:?} — left pending for redelivery",
+                job_id, delivery_count, self.max_attempts, consumer_id, reason
+            );
+            return Ok(());
+        }
+
+        // Exhausted retries: pull the payload back off the stream entry and
+        // move it to the DLQ instead of leaving it pending forever.
+        let range: redis::streams::StreamRangeReply =
+            conn.xrange(&self.stream_key, &stream_id, &stream_id).await?;
+        let payload_bytes = range
+            .ids
+            .first()
+            .and_then(|entry| entry.map.get("payload"))
+            .map(|v| redis::from_redis_value::<Vec<u8>>(v))
+            .transpose()?
+            .ok_or_else(|| -> Box<dyn Error> {
+                Box::new(std::io::Error::other(format!(
+                    "Stream entry {} for job {} has no payload field",
+                    stream_id, job_id
+                )))
+            })?;
+        let payload: JobPayload = self.codec.decode(&payload_bytes)?;
+
+        let queued_job = QueuedJob {
+            job_id: job_id.to_string(),
+            payload,
+            attempts: delivery_count,
+            claimed_at: chrono::Utc::now(),
+        };
+        self.move_to_dlq(
+            &queued_job,
+            reason.unwrap_or_else(|| "max attempts exceeded".to_string()),
+        )
+        .await?;
+
+        let _: i64 = conn
+            .xack(&self.stream_key, &self.consumer_group, &[&stream_id])
+            .await?;
+        let _: i64 = conn.xdel(&self.stream_key, &[&stream_id]).await?;
+        let _: i64 = conn.hdel(self.stream_ids_key(), job_id).await?;
+
         println!(
-            "NACK job {} by consumer {}: {:?}",
-            job_id, consumer_id, reason
+            "Job {} exhausted {} attempts (consumer {}); moved to DLQ",
+            job_id, delivery_count, consumer_id
         );
 
-        // TODO: Implement proper retry logic
-        // Options:
-        // 1. Move to dead letter queue after N retries
-        // 2. Use XCLAIM to reassign to another consumer
-        // 3. Track retry count in job metadata
-
         Ok(())
     }
 
@@ -239,6 +840,147 @@ impl Queue for RedisQueue {
 
         Ok(length)
     }
+
+    /// Move a job to the Dead Letter Queue
+    ///
+    /// Stored as a field in the `{stream_key}:dlq` hash, keyed by job_id, so
+    /// `list_dlq`/`replay_dlq` can look entries up directly instead of
+    /// scanning the whole stream.
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entry = DeadLetterEntry {
+            job_id: job.job_id.clone(),
+            payload: job.payload.clone(),
+            error: reason,
+            attempts: job.attempts,
+            failed_at: chrono::Utc::now(),
+        };
+        let entry_json = serde_json::to_string(&entry)?;
+
+        let _: () = conn.hset(self.dlq_key(), &job.job_id, entry_json).await?;
+
+        println!("Moved job {} to DLQ for stream {}", job.job_id, self.stream_key);
+
+        Ok(())
+    }
+
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entries: HashMap<String, String> = conn.hgetall(self.dlq_key()).await?;
+
+        let mut dlq = Vec::new();
+        for entry_json in entries.values().take(limit) {
+            dlq.push(serde_json::from_str(entry_json)?);
+        }
+
+        Ok(dlq)
+    }
+
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entry_json: Option<String> = conn.hget(self.dlq_key(), job_id).await?;
+        entry_json
+            .map(|json| serde_json::from_str(&json).map_err(Into::into))
+            .transpose()
+    }
+
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        self.get_dlq_length().await
+    }
+
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let entry_json: Option<String> = conn.hget(self.dlq_key(), job_id).await?;
+        let entry_json = entry_json.ok_or_else(|| -> Box<dyn Error> {
+            Box::new(std::io::Error::other(format!(
+                "No DLQ entry found for job {}",
+                job_id
+            )))
+        })?;
+        let entry: DeadLetterEntry = serde_json::from_str(&entry_json)?;
+
+        let _: () = conn.hdel(self.dlq_key(), job_id).await?;
+        self.enqueue(entry.payload).await?;
+
+        Ok(())
+    }
+
+    /// Schedule a job to become available no earlier than `when`
+    ///
+    /// Stores the payload in the `delayed` sorted set, scored by
+    /// [`priority_score`] on `(when, payload.priority())`, instead of
+    /// `XADD`ing it onto the main stream directly.
+    /// [`Self::promote_due_jobs`] (or [`Self::spawn_delayed_job_promoter`])
+    /// moves it onto the stream once it's due, in [`priority_score`] order.
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>> {
+        let mut conn = self.get_connection();
+        let job_id = payload.job_id.clone();
+
+        let score = priority_score(payload.priority(), when.timestamp_millis());
+        let payload_bytes = self.codec.encode(&payload)?;
+        let _: () = conn.zadd(self.delayed_key(), payload_bytes, score).await?;
+
+        println!(
+            "Scheduled job {} on stream {} for {}",
+            job_id, self.stream_key, when
+        );
+
+        Ok(job_id)
+    }
+
+    /// Extend a live worker's claim on `job_id` so it isn't reclaimed by
+    /// [`Self::reclaim_stale`]/[`Self::spawn_reaper`] while still processing
+    ///
+    /// `XCLAIM`s the job's stream entry right back to `consumer_id` with
+    /// `MIN-IDLE-TIME 0`, which resets the entry's idle timer in the
+    /// consumer group's Pending Entries List without changing its
+    /// ownership or delivery count.
+    async fn heartbeat(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        let mut conn = self.get_connection();
+
+        let stream_id: Option<String> = conn.hget(self.stream_ids_key(), job_id).await?;
+        let Some(stream_id) = stream_id else {
+            println!(
+                "No stream ID tracked for job {} (consumer {}); nothing to heartbeat",
+                job_id, consumer_id
+            );
+            return Ok(());
+        };
+
+        let _: Vec<String> = redis::cmd("XCLAIM")
+            .arg(&self.stream_key)
+            .arg(&self.consumer_group)
+            .arg(consumer_id)
+            .arg(0)
+            .arg(&stream_id)
+            .arg("JUSTID")
+            .query_async(&mut conn)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reclaim PEL entries idle longer than [`Self::visibility_timeout`]
+    /// under a dedicated `"reaper"` consumer, same as [`Self::spawn_reaper`]
+    /// does on a schedule
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+        let jobs = self
+            .reclaim_stale(
+                "reaper",
+                self.visibility_timeout.as_millis() as u64,
+                100,
+            )
+            .await?;
+        Ok(jobs.len())
+    }
 }
 
 #[cfg(test)]
@@ -278,4 +1020,153 @@ mod tests {
 
         assert!(result.is_ok(), "Should create RedisQueue with params");
     }
+
+    #[tokio::test]
+    async fn test_nack_moves_to_dlq_after_max_attempts() {
+        if std::env::var("REDIS_URL").is_err() {
+            println!("Skipping test: REDIS_URL not set");
+            return;
+        }
+        let redis_url = std::env::var("REDIS_URL").unwrap();
+
+        let queue = RedisQueue::new(
+            &redis_url,
+            "test:dlq:stream".to_string(),
+            "test:dlq:group".to_string(),
+        )
+        .await
+        .unwrap()
+        .with_max_attempts(2);
+
+        let payload = JobPayload {
+            job_id: "dlq-test-job".to_string(),
+            user_id: uuid::Uuid::new_v4(),
+            question: "Will this end up in the DLQ?".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: chrono::Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+        queue.enqueue(payload.clone()).await.unwrap();
+
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        queue
+            .nack(&job.job_id, "consumer-1", Some("attempt 1".to_string()))
+            .await
+            .unwrap();
+
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        assert_eq!(job.attempts, 2, "second delivery should report attempts=2");
+        queue
+            .nack(&job.job_id, "consumer-1", Some("attempt 2".to_string()))
+            .await
+            .unwrap();
+
+        let dlq_length = queue.get_dlq_length().await.unwrap();
+        assert_eq!(dlq_length, 1, "job should have been moved to the DLQ");
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_at_promotes_once_due() {
+        if std::env::var("REDIS_URL").is_err() {
+            println!("Skipping test: REDIS_URL not set");
+            return;
+        }
+        let redis_url = std::env::var("REDIS_URL").unwrap();
+
+        let queue = RedisQueue::new(
+            &redis_url,
+            "test:delayed:stream".to_string(),
+            "test:delayed:group".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let payload = JobPayload {
+            job_id: "delayed-test-job".to_string(),
+            user_id: uuid::Uuid::new_v4(),
+            question: "Will this be delayed?".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: chrono::Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+        queue
+            .enqueue_at(payload, chrono::Utc::now() - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+
+        let promoted = queue.promote_due_jobs(100).await.unwrap();
+        assert_eq!(promoted, 1);
+
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, "delayed-test-job");
+    }
+
+    #[tokio::test]
+    async fn test_dequeue_prefers_higher_priority_job_regardless_of_enqueue_order() {
+        if std::env::var("REDIS_URL").is_err() {
+            println!("Skipping test: REDIS_URL not set");
+            return;
+        }
+        let redis_url = std::env::var("REDIS_URL").unwrap();
+
+        let queue = RedisQueue::new(
+            &redis_url,
+            "test:priority:stream".to_string(),
+            "test:priority:group".to_string(),
+        )
+        .await
+        .unwrap();
+
+        let mut low = JobPayload {
+            job_id: "low-priority-job".to_string(),
+            user_id: uuid::Uuid::new_v4(),
+            question: "low priority, enqueued first".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: chrono::Utc::now(),
+            scheduled_at: None,
+            priority: 1,
+            metadata: serde_json::json!({}),
+        };
+        low.priority = 1;
+        queue.enqueue(low).await.unwrap();
+
+        let mut high = JobPayload {
+            job_id: "high-priority-job".to_string(),
+            user_id: uuid::Uuid::new_v4(),
+            question: "high priority, enqueued second".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: chrono::Utc::now(),
+            scheduled_at: None,
+            priority: 9,
+            metadata: serde_json::json!({}),
+        };
+        high.priority = 9;
+        queue.enqueue(high).await.unwrap();
+
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        assert_eq!(
+            job.job_id, "high-priority-job",
+            "higher-priority job should dequeue first even though it was enqueued second"
+        );
+    }
 }
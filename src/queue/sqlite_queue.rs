@@ -0,0 +1,666 @@
+//! SQLite-backed persistent queue implementation
+//!
+//! [`crate::queue::inmemory_queue::InMemoryQueue`] loses all pending and
+//! processing jobs on restart, which makes it unsuitable for a real
+//! deployment. This module provides a [`Queue`] implementation backed by a
+//! SQLite table so tarot-reading jobs survive process restarts, while
+//! keeping the same zero-external-dependency story `InMemoryQueue` offers
+//! (no Redis required).
+//!
+//! # Schema
+//!
+//! A single `jobs` table holds the serialized [`JobPayload`] plus enough
+//! columns to reconstruct [`QueuedJob`]/[`DeadLetterEntry`] without a second
+//! table: `job_id`, `payload` (JSON blob), `attempts`, `claimed_at`,
+//! `claimed_by`, `not_before` (the `enqueue_at` run time), `state`
+//! (`pending`/`processing`/`dlq`), and `dlq_reason`/`failed_at` (populated
+//! only once a job reaches `dlq`). The embedded migration runs
+//! automatically in [`SqliteQueue::new`], so there's no separate migration
+//! step to run out-of-band.
+//!
+//! `dequeue` claims the oldest pending, due row with a single atomic
+//! `UPDATE ... WHERE job_id = (SELECT ...) RETURNING ...` statement rather
+//! than a separate transaction, so two concurrent workers can never
+//! observe and claim the same row — SQLite executes the whole statement,
+//! subquery included, as one atomic step. `ack` deletes the row outright
+//! rather than flipping it to a `done` state, since nothing in this crate
+//! reads completed jobs back out once they're acked; `test_ack_removes_job`
+//! and `test_concurrent_dequeue_never_double_claims` below cover ack removal
+//! and single-consumer claiming.
+
+use crate::queue::{DeadLetterEntry, JobPayload, Queue, QueuedJob};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use sqlx::Row;
+use std::error::Error;
+use std::time::Duration;
+
+/// Default visibility timeout: how long a claimed row survives without a
+/// [`Queue::heartbeat`] before [`Queue::reclaim_expired`] considers it
+/// abandoned and moves it back to `pending`
+const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default number of delivery attempts before `nack` dead-letters a job
+/// instead of requeueing it
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+const MIGRATION_SQL: &str = r#"
+CREATE TABLE IF NOT EXISTS jobs (
+    job_id TEXT PRIMARY KEY,
+    payload TEXT NOT NULL,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    claimed_at TEXT,
+    claimed_by TEXT,
+    created_at TEXT NOT NULL,
+    not_before TEXT NOT NULL,
+    state TEXT NOT NULL DEFAULT 'pending',
+    dlq_reason TEXT,
+    failed_at TEXT
+);
+CREATE INDEX IF NOT EXISTS idx_jobs_state_not_before ON jobs (state, not_before, created_at);
+"#;
+
+/// SQLite-backed queue implementation
+///
+/// Selected in place of [`crate::queue::inmemory_queue::InMemoryQueue`]
+/// whenever jobs need to survive a process restart without standing up
+/// Redis.
+pub struct SqliteQueue {
+    pool: SqlitePool,
+    /// How long a claim survives without a heartbeat before being reclaimed
+    visibility_timeout: Duration,
+    /// How many delivery attempts a job gets before `nack` dead-letters it
+    max_attempts: u32,
+}
+
+impl SqliteQueue {
+    /// Open (creating if necessary) a SQLite-backed queue at `db_path`
+    ///
+    /// Runs the embedded migration, so the `jobs` table always exists by
+    /// the time this returns.
+    ///
+    /// # Arguments
+    ///
+    /// * `db_path` - Filesystem path to the SQLite database file (use
+    ///   `:memory:` for an ephemeral, process-local queue)
+    pub async fn new(db_path: &str) -> Result<Self, Box<dyn Error>> {
+        let url = if db_path == ":memory:" {
+            "sqlite::memory:".to_string()
+        } else {
+            format!("sqlite://{}?mode=rwc", db_path)
+        };
+
+        let pool = SqlitePoolOptions::new().max_connections(1).connect(&url).await?;
+
+        for statement in MIGRATION_SQL.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            sqlx::query(statement).execute(&pool).await?;
+        }
+
+        Ok(Self {
+            pool,
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        })
+    }
+
+    /// Override the default visibility timeout
+    pub fn with_visibility_timeout(mut self, visibility_timeout: Duration) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    /// Override how many delivery attempts a job gets before `nack`
+    /// dead-letters it instead of requeueing it
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    fn row_to_queued_job(
+        job_id: String,
+        payload_json: String,
+        attempts: i64,
+        claimed_at: Option<String>,
+    ) -> Result<QueuedJob, Box<dyn Error>> {
+        let payload: JobPayload = serde_json::from_str(&payload_json)?;
+        let claimed_at = match claimed_at {
+            Some(ts) => DateTime::parse_from_rfc3339(&ts)?.with_timezone(&Utc),
+            None => Utc::now(),
+        };
+
+        Ok(QueuedJob {
+            job_id,
+            payload,
+            attempts: attempts as u32,
+            claimed_at,
+        })
+    }
+}
+
+#[async_trait]
+impl Queue for SqliteQueue {
+    /// Insert a pending row for `payload`, immediately due
+    async fn enqueue(&self, payload: JobPayload) -> Result<String, Box<dyn Error>> {
+        self.enqueue_at(payload, Utc::now()).await
+    }
+
+    /// Atomically claim the oldest pending, due job and mark it processing
+    ///
+    /// The `UPDATE ... WHERE job_id = (SELECT ...)` form claims and bumps
+    /// `attempts` in a single statement, so concurrent workers calling this
+    /// never claim the same row.
+    async fn dequeue(&self, consumer_id: &str) -> Result<Option<QueuedJob>, Box<dyn Error>> {
+        let now = Utc::now().to_rfc3339();
+
+        let row = sqlx::query(
+            r#"
+            UPDATE jobs
+            SET state = 'processing', attempts = attempts + 1, claimed_at = ?1, claimed_by = ?2
+            WHERE job_id = (
+                SELECT job_id FROM jobs
+                WHERE state = 'pending' AND not_before <= ?1
+                ORDER BY created_at ASC
+                LIMIT 1
+            )
+            RETURNING job_id, payload, attempts
+            "#,
+        )
+        .bind(&now)
+        .bind(consumer_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let job_id: String = row.try_get("job_id")?;
+        let payload_json: String = row.try_get("payload")?;
+        let attempts: i64 = row.try_get("attempts")?;
+
+        Ok(Some(Self::row_to_queued_job(
+            job_id,
+            payload_json,
+            attempts,
+            Some(now),
+        )?))
+    }
+
+    /// Acknowledge successful completion by deleting the row
+    async fn ack(&self, job_id: &str, _consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        sqlx::query("DELETE FROM jobs WHERE job_id = ?1 AND state = 'processing'")
+            .bind(job_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Reset a processing job back to pending for redelivery, unless its
+    /// delivery count has reached [`Self::max_attempts`] — in which case it's
+    /// moved to the Dead Letter Queue instead of looping forever
+    async fn nack(
+        &self,
+        job_id: &str,
+        _consumer_id: &str,
+        reason: Option<String>,
+    ) -> Result<(), Box<dyn Error>> {
+        if let Some(ref r) = reason {
+            eprintln!("Job {} NACK'd: {}", job_id, r);
+        }
+
+        let row = sqlx::query(
+            "SELECT payload, attempts FROM jobs WHERE job_id = ?1 AND state = 'processing'",
+        )
+        .bind(job_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(());
+        };
+
+        let payload_json: String = row.try_get("payload")?;
+        let attempts: i64 = row.try_get("attempts")?;
+
+        if attempts as u32 >= self.max_attempts {
+            let payload: JobPayload = serde_json::from_str(&payload_json)?;
+            let job = QueuedJob {
+                job_id: job_id.to_string(),
+                payload,
+                attempts: attempts as u32,
+                claimed_at: Utc::now(),
+            };
+            let reason =
+                reason.unwrap_or_else(|| format!("Exceeded max attempts ({})", self.max_attempts));
+            self.move_to_dlq(&job, reason).await?;
+        } else {
+            sqlx::query(
+                "UPDATE jobs SET state = 'pending', claimed_at = NULL, claimed_by = NULL \
+                 WHERE job_id = ?1 AND state = 'processing'",
+            )
+            .bind(job_id)
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Count pending, due jobs
+    async fn get_queue_length(&self) -> Result<usize, Box<dyn Error>> {
+        let now = Utc::now().to_rfc3339();
+        let row = sqlx::query(
+            "SELECT COUNT(*) as count FROM jobs WHERE state = 'pending' AND not_before <= ?1",
+        )
+        .bind(&now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let count: i64 = row.try_get("count")?;
+        Ok(count as usize)
+    }
+
+    /// Move a job to the Dead Letter Queue by flipping its state to `dlq`
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>> {
+        let now = Utc::now().to_rfc3339();
+        let payload_json = serde_json::to_string(&job.payload)?;
+
+        sqlx::query(
+            "UPDATE jobs \
+             SET state = 'dlq', attempts = ?1, payload = ?2, dlq_reason = ?3, failed_at = ?4 \
+             WHERE job_id = ?5",
+        )
+        .bind(job.attempts as i64)
+        .bind(&payload_json)
+        .bind(&reason)
+        .bind(&now)
+        .bind(&job.job_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// List up to `limit` jobs currently sitting in the Dead Letter Queue
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+        let rows = sqlx::query(
+            "SELECT job_id, payload, attempts, dlq_reason, failed_at FROM jobs \
+             WHERE state = 'dlq' ORDER BY failed_at ASC LIMIT ?1",
+        )
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut entries = Vec::with_capacity(rows.len());
+        for row in rows {
+            let job_id: String = row.try_get("job_id")?;
+            let payload_json: String = row.try_get("payload")?;
+            let attempts: i64 = row.try_get("attempts")?;
+            let reason: Option<String> = row.try_get("dlq_reason")?;
+            let failed_at: Option<String> = row.try_get("failed_at")?;
+
+            entries.push(DeadLetterEntry {
+                job_id,
+                payload: serde_json::from_str(&payload_json)?,
+                error: reason.unwrap_or_default(),
+                attempts: attempts as u32,
+                failed_at: match failed_at {
+                    Some(ts) => DateTime::parse_from_rfc3339(&ts)?.with_timezone(&Utc),
+                    None => Utc::now(),
+                },
+            });
+        }
+
+        Ok(entries)
+    }
+
+    /// Look up a single Dead Letter Queue row by `job_id`
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        let row = sqlx::query(
+            "SELECT job_id, payload, attempts, dlq_reason, failed_at FROM jobs \
+             WHERE state = 'dlq' AND job_id = ?1",
+        )
+        .bind(job_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let job_id: String = row.try_get("job_id")?;
+        let payload_json: String = row.try_get("payload")?;
+        let attempts: i64 = row.try_get("attempts")?;
+        let reason: Option<String> = row.try_get("dlq_reason")?;
+        let failed_at: Option<String> = row.try_get("failed_at")?;
+
+        Ok(Some(DeadLetterEntry {
+            job_id,
+            payload: serde_json::from_str(&payload_json)?,
+            error: reason.unwrap_or_default(),
+            attempts: attempts as u32,
+            failed_at: match failed_at {
+                Some(ts) => DateTime::parse_from_rfc3339(&ts)?.with_timezone(&Utc),
+                None => Utc::now(),
+            },
+        }))
+    }
+
+    /// Count rows currently sitting in the Dead Letter Queue
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM jobs WHERE state = 'dlq'")
+            .fetch_one(&self.pool)
+            .await?;
+
+        let count: i64 = row.try_get("count")?;
+        Ok(count as usize)
+    }
+
+    /// Re-enqueue a dead job, resetting its attempt counter
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>> {
+        let now = Utc::now().to_rfc3339();
+
+        let result = sqlx::query(
+            "UPDATE jobs \
+             SET state = 'pending', attempts = 0, claimed_at = NULL, claimed_by = NULL, \
+                 dlq_reason = NULL, failed_at = NULL, not_before = ?1 \
+             WHERE job_id = ?2 AND state = 'dlq'",
+        )
+        .bind(&now)
+        .bind(job_id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(Box::new(std::io::Error::other(format!(
+                "No DLQ entry found for job {}",
+                job_id
+            ))));
+        }
+
+        Ok(())
+    }
+
+    /// Insert a pending row due no earlier than `when`
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>> {
+        let job_id = payload.job_id.clone();
+        let payload_json = serde_json::to_string(&payload)?;
+        let now = Utc::now().to_rfc3339();
+
+        sqlx::query(
+            "INSERT INTO jobs (job_id, payload, attempts, created_at, not_before, state) \
+             VALUES (?1, ?2, 0, ?3, ?4, 'pending')",
+        )
+        .bind(&job_id)
+        .bind(&payload_json)
+        .bind(&now)
+        .bind(when.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(job_id)
+    }
+
+    /// Refresh `claimed_at` on a row still owned by `consumer_id`
+    ///
+    /// No-ops if the job isn't currently claimed by that consumer (already
+    /// acked/nacked, or claimed by someone else), per the trait's contract.
+    async fn heartbeat(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        let now = Utc::now().to_rfc3339();
+
+        sqlx::query(
+            "UPDATE jobs SET claimed_at = ?1 \
+             WHERE job_id = ?2 AND state = 'processing' AND claimed_by = ?3",
+        )
+        .bind(&now)
+        .bind(job_id)
+        .bind(consumer_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Move any `processing` row whose `claimed_at` predates the visibility
+    /// timeout back to `pending`, incrementing nothing here — `dequeue`
+    /// bumps `attempts` again the next time the row is claimed, same as a
+    /// [`Queue::nack`]'d job would be
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+        let cutoff = (Utc::now()
+            - chrono::Duration::from_std(self.visibility_timeout)
+                .unwrap_or_else(|_| chrono::Duration::seconds(30)))
+        .to_rfc3339();
+
+        let result = sqlx::query(
+            "UPDATE jobs SET state = 'pending', claimed_at = NULL, claimed_by = NULL \
+             WHERE state = 'processing' AND claimed_at <= ?1",
+        )
+        .bind(&cutoff)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn create_test_payload(question: &str) -> JobPayload {
+        JobPayload {
+            job_id: Uuid::new_v4().to_string(),
+            user_id: Uuid::new_v4(),
+            question: question.to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_queue_is_empty() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        assert_eq!(queue.get_queue_length().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_and_dequeue_roundtrip() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        assert_eq!(queue.get_queue_length().await.unwrap(), 1);
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, job_id);
+        assert_eq!(job.attempts, 1);
+        assert_eq!(queue.get_queue_length().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dequeue_empty_returns_none() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        assert!(queue.dequeue("worker-1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_ack_removes_job() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        queue.dequeue("worker-1").await.unwrap();
+        queue.ack(&job_id, "worker-1").await.unwrap();
+
+        assert!(queue.list_dlq(10).await.unwrap().is_empty());
+        assert_eq!(queue.get_queue_length().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_nack_requeues_job() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        queue.dequeue("worker-1").await.unwrap();
+        queue
+            .nack(&job_id, "worker-1", Some("test".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(queue.get_queue_length().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_nack_dead_letters_job_once_max_attempts_reached() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap().with_max_attempts(1);
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.attempts, 1);
+
+        queue
+            .nack(&job_id, "worker-1", Some("boom".to_string()))
+            .await
+            .unwrap();
+
+        assert_eq!(queue.get_queue_length().await.unwrap(), 0);
+        assert_eq!(queue.get_dead_letter_length().await.unwrap(), 1);
+
+        let dlq = queue.list_dlq(10).await.unwrap();
+        assert_eq!(dlq[0].job_id, job_id);
+        assert_eq!(dlq[0].error, "boom");
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_dequeue_never_double_claims() {
+        let queue = std::sync::Arc::new(SqliteQueue::new(":memory:").await.unwrap());
+        for i in 0..5 {
+            queue
+                .enqueue(create_test_payload(&format!("test-{}", i)))
+                .await
+                .unwrap();
+        }
+
+        let mut handles = Vec::new();
+        for i in 0..5 {
+            let queue = queue.clone();
+            handles.push(tokio::spawn(async move {
+                queue.dequeue(&format!("worker-{}", i)).await.unwrap()
+            }));
+        }
+
+        let mut claimed = std::collections::HashSet::new();
+        for handle in handles {
+            if let Some(job) = handle.await.unwrap() {
+                assert!(claimed.insert(job.job_id), "job claimed more than once");
+            }
+        }
+        assert_eq!(claimed.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_move_to_dlq_and_replay() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+
+        queue
+            .move_to_dlq(&job, "max attempts exceeded".to_string())
+            .await
+            .unwrap();
+
+        let dlq = queue.list_dlq(10).await.unwrap();
+        assert_eq!(dlq.len(), 1);
+        assert_eq!(dlq[0].job_id, job_id);
+        assert_eq!(dlq[0].error, "max attempts exceeded");
+
+        queue.replay_dlq(&job_id).await.unwrap();
+        assert!(queue.list_dlq(10).await.unwrap().is_empty());
+        assert_eq!(queue.get_queue_length().await.unwrap(), 1);
+
+        let replayed = queue.dequeue("worker-2").await.unwrap().unwrap();
+        assert_eq!(replayed.job_id, job_id);
+        assert_eq!(replayed.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_replay_dlq_errors_when_job_not_found() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        assert!(queue.replay_dlq("does-not-exist").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_at_future_time_does_not_appear_in_queue_length() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        let payload = create_test_payload("test");
+
+        queue
+            .enqueue_at(payload, Utc::now() + chrono::Duration::hours(1))
+            .await
+            .unwrap();
+
+        assert_eq!(queue.get_queue_length().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_reclaim_expired_requeues_abandoned_claim() {
+        let queue = SqliteQueue::new(":memory:")
+            .await
+            .unwrap()
+            .with_visibility_timeout(Duration::from_millis(20));
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue.enqueue(payload).await.unwrap();
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.attempts, 1);
+
+        // Worker crashes without ack/nack; nothing reclaimed yet.
+        assert_eq!(queue.reclaim_expired().await.unwrap(), 0);
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(queue.reclaim_expired().await.unwrap(), 1);
+        let reclaimed = queue.dequeue("worker-2").await.unwrap().unwrap();
+        assert_eq!(reclaimed.job_id, job_id);
+        assert_eq!(reclaimed.attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_at_past_time_is_immediately_dequeuable() {
+        let queue = SqliteQueue::new(":memory:").await.unwrap();
+        let payload = create_test_payload("test");
+        let job_id = payload.job_id.clone();
+
+        queue
+            .enqueue_at(payload, Utc::now() - chrono::Duration::seconds(1))
+            .await
+            .unwrap();
+
+        let job = queue.dequeue("worker-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, job_id);
+    }
+}
@@ -0,0 +1,174 @@
+//! Poll-timer instrumentation decorator for any [`Queue`] backend
+//!
+//! Wraps any [`Queue`] implementation so every trait method call is
+//! instrumented with [`crate::worker::WithPollTimer`], logging a structured
+//! warning if a single call stalls — e.g. a Redis latency spike or lock
+//! contention on [`crate::queue::inmemory_queue::InMemoryQueue`]'s internal
+//! `Mutex`. Any backend gets this for free just by being wrapped: no changes
+//! to the backend itself are needed.
+
+use crate::queue::{DeadLetterEntry, JobPayload, Queue, QueuedJob};
+use crate::worker::WithPollTimer;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::error::Error;
+
+/// Decorates any [`Queue`] implementation with poll-timer instrumentation
+pub struct TimedQueue<Q> {
+    inner: Q,
+}
+
+impl<Q: Queue> TimedQueue<Q> {
+    /// Wrap `inner` so every `Queue` method call is timed
+    pub fn new(inner: Q) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<Q: Queue> Queue for TimedQueue<Q> {
+    async fn enqueue(&self, payload: JobPayload) -> Result<String, Box<dyn Error>> {
+        self.inner
+            .enqueue(payload)
+            .with_poll_timer("Queue::enqueue")
+            .await
+    }
+
+    async fn dequeue(&self, consumer_id: &str) -> Result<Option<QueuedJob>, Box<dyn Error>> {
+        self.inner
+            .dequeue(consumer_id)
+            .with_poll_timer("Queue::dequeue")
+            .await
+    }
+
+    async fn ack(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        self.inner
+            .ack(job_id, consumer_id)
+            .with_poll_timer("Queue::ack")
+            .with_job_id(job_id)
+            .await
+    }
+
+    async fn nack(
+        &self,
+        job_id: &str,
+        consumer_id: &str,
+        reason: Option<String>,
+    ) -> Result<(), Box<dyn Error>> {
+        self.inner
+            .nack(job_id, consumer_id, reason)
+            .with_poll_timer("Queue::nack")
+            .with_job_id(job_id)
+            .await
+    }
+
+    async fn get_queue_length(&self) -> Result<usize, Box<dyn Error>> {
+        self.inner
+            .get_queue_length()
+            .with_poll_timer("Queue::get_queue_length")
+            .await
+    }
+
+    async fn move_to_dlq(&self, job: &QueuedJob, reason: String) -> Result<(), Box<dyn Error>> {
+        let job_id = job.job_id.clone();
+        self.inner
+            .move_to_dlq(job, reason)
+            .with_poll_timer("Queue::move_to_dlq")
+            .with_job_id(job_id)
+            .await
+    }
+
+    async fn list_dlq(&self, limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+        self.inner
+            .list_dlq(limit)
+            .with_poll_timer("Queue::list_dlq")
+            .await
+    }
+
+    async fn get_dlq_entry(&self, job_id: &str) -> Result<Option<DeadLetterEntry>, Box<dyn Error>> {
+        self.inner
+            .get_dlq_entry(job_id)
+            .with_poll_timer("Queue::get_dlq_entry")
+            .with_job_id(job_id)
+            .await
+    }
+
+    async fn get_dead_letter_length(&self) -> Result<usize, Box<dyn Error>> {
+        self.inner
+            .get_dead_letter_length()
+            .with_poll_timer("Queue::get_dead_letter_length")
+            .await
+    }
+
+    async fn replay_dlq(&self, job_id: &str) -> Result<(), Box<dyn Error>> {
+        self.inner
+            .replay_dlq(job_id)
+            .with_poll_timer("Queue::replay_dlq")
+            .with_job_id(job_id)
+            .await
+    }
+
+    async fn enqueue_at(
+        &self,
+        payload: JobPayload,
+        when: DateTime<Utc>,
+    ) -> Result<String, Box<dyn Error>> {
+        self.inner
+            .enqueue_at(payload, when)
+            .with_poll_timer("Queue::enqueue_at")
+            .await
+    }
+
+    async fn heartbeat(&self, job_id: &str, consumer_id: &str) -> Result<(), Box<dyn Error>> {
+        self.inner
+            .heartbeat(job_id, consumer_id)
+            .with_poll_timer("Queue::heartbeat")
+            .with_job_id(job_id)
+            .await
+    }
+
+    async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+        self.inner
+            .reclaim_expired()
+            .with_poll_timer("Queue::reclaim_expired")
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queue::inmemory_queue::InMemoryQueue;
+    use uuid::Uuid;
+
+    fn sample_payload() -> JobPayload {
+        JobPayload {
+            job_id: "timed-test".to_string(),
+            user_id: Uuid::new_v4(),
+            question: "Will this be timed?".to_string(),
+            card_count: 3,
+            schema_version: "1".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_timed_queue_delegates_enqueue_and_dequeue() {
+        let queue = TimedQueue::new(InMemoryQueue::new());
+
+        let job_id = queue.enqueue(sample_payload()).await.unwrap();
+        assert_eq!(queue.get_queue_length().await.unwrap(), 1);
+
+        let job = queue.dequeue("consumer-1").await.unwrap().unwrap();
+        assert_eq!(job.job_id, job_id);
+
+        queue.ack(&job_id, "consumer-1").await.unwrap();
+        assert_eq!(queue.get_queue_length().await.unwrap(), 0);
+    }
+}
@@ -40,10 +40,83 @@ pub struct JobPayload {
     /// Timestamp when the job was created
     pub created_at: DateTime<Utc>,
 
+    /// Run time carried with the payload — `None` for an immediate job.
+    /// No queue backend writes or reads this field:
+    /// [`crate::queue::Queue::enqueue_at`]/
+    /// [`crate::queue::Queue::enqueue_delayed`] take the run time as their
+    /// own argument, and each backend stages and orders deferred jobs by
+    /// that argument rather than by this field.
+    pub scheduled_at: Option<DateTime<Utc>>,
+
+    /// Dequeue priority — higher runs first among otherwise-ready jobs
+    pub priority: u8,
+
     /// Additional metadata as flexible JSON
     pub metadata: serde_json::Value,
 }
 
+/// Schema version this build knows how to process
+///
+/// [`JobPayload::validate`] rejects any payload whose `schema_version`
+/// doesn't match, so an old/future payload shape is dead-lettered instead
+/// of crashing a worker trying to interpret fields it doesn't expect.
+pub const SUPPORTED_SCHEMA_VERSION: &str = "1";
+
+impl JobPayload {
+    /// Check this payload is well-formed enough to hand to a worker
+    ///
+    /// Catches the poison-message cases a worker can't sensibly retry its
+    /// way out of: an unsupported `schema_version`, an empty `prompt_version`,
+    /// or a `card_count` outside the supported tarot spreads (3 or 5 cards).
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason on the first validation failure.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.schema_version != SUPPORTED_SCHEMA_VERSION {
+            return Err(format!(
+                "unsupported schema_version '{}' (expected '{}')",
+                self.schema_version, SUPPORTED_SCHEMA_VERSION
+            ));
+        }
+
+        if self.prompt_version.trim().is_empty() {
+            return Err("prompt_version must not be empty".to_string());
+        }
+
+        if self.card_count != 3 && self.card_count != 5 {
+            return Err(format!(
+                "unsupported card_count {} (expected 3 or 5)",
+                self.card_count
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Which [`JobType`] this payload should be routed as
+    ///
+    /// `JobPayload` has no dedicated `job_type` column yet, so this reads an
+    /// optional `"job_type"` string out of the free-form `metadata` bag
+    /// (`"notification"` / `"maintenance"`) and falls back to
+    /// [`JobType::TarotReading`], since every payload enqueued today is a
+    /// tarot reading. Once callers start tagging non-tarot jobs through
+    /// `metadata`, a [`crate::worker::dispatch::Dispatcher`] can route on the
+    /// result without any schema migration.
+    pub fn job_type(&self) -> JobType {
+        match self.metadata.get("job_type").and_then(|v| v.as_str()) {
+            Some("notification") => JobType::Notification,
+            Some("maintenance") => JobType::Maintenance,
+            _ => JobType::TarotReading,
+        }
+    }
+
+    /// Dequeue priority for this payload — higher runs first
+    pub fn priority(&self) -> u8 {
+        self.priority
+    }
+}
+
 /// Job metadata structure
 ///
 /// Contains additional contextual information about the job.
@@ -57,12 +130,32 @@ pub struct JobMetadata {
     pub source: String,
 }
 
+/// A job that exhausted its retries (or hit a permanent error) and was
+/// moved to the Dead Letter Queue instead of being retried again
+///
+/// Preserves enough context — the failing error, how many times it was
+/// attempted, and when it died — for an operator to diagnose the failure
+/// or decide whether to [`crate::queue::Queue::replay_dlq`] it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadLetterEntry {
+    /// Unique identifier of the job that died
+    pub job_id: String,
+    /// The job data, preserved so it can be replayed
+    pub payload: JobPayload,
+    /// String form of the error that caused the final failure
+    pub error: String,
+    /// Number of attempts made before the job was given up on
+    pub attempts: u32,
+    /// When the job was moved to the DLQ
+    pub failed_at: DateTime<Utc>,
+}
+
 /// Job type enumeration
 ///
 /// Defines different types of jobs that can be processed.
 /// This allows the queue system to handle multiple job types
 /// in the future.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum JobType {
     /// Tarot reading job
     TarotReading,
@@ -90,6 +183,8 @@ mod tests {
             dedupe_key: None,
             trace_id: None,
             created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
             metadata: serde_json::json!({}),
         };
 
@@ -109,6 +204,53 @@ mod tests {
         assert!(json.contains("mobile"));
     }
 
+    #[test]
+    fn test_validate_accepts_well_formed_payload() {
+        let payload = JobPayload {
+            job_id: "test-123".to_string(),
+            user_id: Uuid::new_v4(),
+            question: "Test question".to_string(),
+            card_count: 3,
+            schema_version: SUPPORTED_SCHEMA_VERSION.to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+
+        assert!(payload.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_unsupported_schema_version() {
+        let mut payload = JobPayload {
+            job_id: "test-123".to_string(),
+            user_id: Uuid::new_v4(),
+            question: "Test question".to_string(),
+            card_count: 3,
+            schema_version: "999".to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+        assert!(payload.validate().is_err());
+
+        payload.schema_version = SUPPORTED_SCHEMA_VERSION.to_string();
+        payload.card_count = 4;
+        assert!(payload.validate().is_err());
+
+        payload.card_count = 3;
+        payload.prompt_version = "".to_string();
+        assert!(payload.validate().is_err());
+    }
+
     #[test]
     fn test_job_type_variants() {
         let types = vec![
@@ -123,4 +265,57 @@ mod tests {
             assert_eq!(job_type, deserialized);
         }
     }
+
+    #[test]
+    fn test_job_type_reads_metadata_hint_and_falls_back_to_tarot_reading() {
+        let mut payload = JobPayload {
+            job_id: "test-123".to_string(),
+            user_id: Uuid::new_v4(),
+            question: "Test question".to_string(),
+            card_count: 3,
+            schema_version: SUPPORTED_SCHEMA_VERSION.to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+        assert_eq!(payload.job_type(), JobType::TarotReading);
+
+        payload.metadata = serde_json::json!({"job_type": "notification"});
+        assert_eq!(payload.job_type(), JobType::Notification);
+
+        payload.metadata = serde_json::json!({"job_type": "maintenance"});
+        assert_eq!(payload.job_type(), JobType::Maintenance);
+
+        payload.metadata = serde_json::json!({"job_type": "unknown-future-type"});
+        assert_eq!(payload.job_type(), JobType::TarotReading);
+    }
+
+    #[test]
+    fn test_priority_reads_the_priority_field() {
+        let mut payload = JobPayload {
+            job_id: "test-123".to_string(),
+            user_id: Uuid::new_v4(),
+            question: "Test question".to_string(),
+            card_count: 3,
+            schema_version: SUPPORTED_SCHEMA_VERSION.to_string(),
+            prompt_version: "v2025-11-20-a".to_string(),
+            dedupe_key: None,
+            trace_id: None,
+            created_at: Utc::now(),
+            scheduled_at: None,
+            priority: 0,
+            metadata: serde_json::json!({}),
+        };
+        assert_eq!(payload.priority(), 0);
+
+        payload.priority = 9;
+        assert_eq!(payload.priority(), 9);
+
+        payload.priority = u8::MAX;
+        assert_eq!(payload.priority(), u8::MAX);
+    }
 }
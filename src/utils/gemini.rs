@@ -2,11 +2,206 @@
 //!
 //! Provides interface for communicating with Google Gemini LLM API.
 
+use crate::worker::retry::{BackoffStrategy, ExponentialBackoff};
+use std::fmt;
+use std::time::Duration;
+
+/// Generative Language REST endpoint, parameterized on model name
+const GEMINI_API_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta/models";
+
+/// Model used for tarot reading prompts
+const GEMINI_MODEL: &str = "gemini-1.5-flash";
+
+/// Default number of attempts (including the first) before giving up on a
+/// retryable failure
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default per-request timeout
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Backoff curve applied between retryable attempts
+const RETRY_BACKOFF: ExponentialBackoff = ExponentialBackoff {
+    base_delay: Duration::from_millis(250),
+    max_delay: Duration::from_secs(5),
+    multiplier: 2.0,
+};
+
+/// Errors `call_gemini` can fail with
+///
+/// Split by failure class (rather than a single `String`, as the stub had)
+/// so callers can branch on what went wrong: [`Self::Auth`] and
+/// [`Self::Api`] with a 4xx code mean retrying won't help, while
+/// [`Self::Network`], [`Self::Timeout`], and [`Self::RateLimited`] are worth
+/// retrying with backoff — which `call_gemini` already does internally, so
+/// callers only see one of these after the retry budget is exhausted.
+#[derive(Debug)]
+pub enum GeminiError {
+    /// Transport-level failure (DNS, connection reset, TLS, ...)
+    Network(String),
+    /// The request did not complete before its timeout
+    Timeout,
+    /// HTTP 429 — too many requests
+    RateLimited,
+    /// `GEMINI_API_KEY` missing, or the API rejected it (HTTP 401/403)
+    Auth(String),
+    /// The response didn't parse into the expected candidate-text shape
+    BadResponse(String),
+    /// The API returned a non-auth, non-rate-limit error status
+    Api { code: u16, message: String },
+}
+
+impl fmt::Display for GeminiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GeminiError::Network(msg) => write!(f, "Gemini network error: {}", msg),
+            GeminiError::Timeout => write!(f, "Gemini request timed out"),
+            GeminiError::RateLimited => write!(f, "Gemini rate limit exceeded"),
+            GeminiError::Auth(msg) => write!(f, "Gemini auth error: {}", msg),
+            GeminiError::BadResponse(msg) => {
+                write!(f, "Gemini returned an unparseable response: {}", msg)
+            }
+            GeminiError::Api { code, message } => {
+                write!(f, "Gemini API error {}: {}", code, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeminiError {}
+
+impl GeminiError {
+    /// Whether retrying the same request might succeed: transient network
+    /// blips, timeouts, rate limiting, and 5xx responses are; auth failures
+    /// and other 4xx API errors are not
+    fn is_retryable(&self) -> bool {
+        match self {
+            GeminiError::Network(_) | GeminiError::Timeout | GeminiError::RateLimited => true,
+            GeminiError::Auth(_) | GeminiError::BadResponse(_) => false,
+            GeminiError::Api { code, .. } => *code >= 500,
+        }
+    }
+}
+
+/// Minimal shape of a Generative Language `generateContent` response —
+/// just enough to pull the first candidate's text back out
+#[derive(serde::Deserialize)]
+struct GenerateContentResponse {
+    candidates: Vec<Candidate>,
+}
+
+#[derive(serde::Deserialize)]
+struct Candidate {
+    content: CandidateContent,
+}
+
+#[derive(serde::Deserialize)]
+struct CandidateContent {
+    parts: Vec<CandidatePart>,
+}
+
+#[derive(serde::Deserialize)]
+struct CandidatePart {
+    text: String,
+}
+
+/// POST `prompt` to the Generative Language REST endpoint and return the
+/// first candidate's text
+///
+/// Thin transport with no retry logic of its own, so [`call_gemini`]'s
+/// retry wrapper can drive it directly and tests can swap it for a mock
+/// without pulling in the backoff loop.
+async fn generate_content(
+    client: &reqwest::Client,
+    api_key: &str,
+    prompt: &str,
+    timeout: Duration,
+) -> Result<String, GeminiError> {
+    // The API key goes in the `x-goog-api-key` header rather than the `key`
+    // query param: `reqwest::Error`'s `Display` impl includes the failing
+    // request's URL, so a key in the URL would leak into
+    // `GeminiError::Network`'s message (and from there into logs) on any
+    // network-level failure.
+    let url = format!("{}/{}:generateContent", GEMINI_API_BASE_URL, GEMINI_MODEL);
+
+    let body = serde_json::json!({
+        "contents": [{
+            "parts": [{ "text": prompt }]
+        }]
+    });
+
+    let response = client
+        .post(&url)
+        .header("x-goog-api-key", api_key)
+        .timeout(timeout)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                GeminiError::Timeout
+            } else {
+                GeminiError::Network(e.to_string())
+            }
+        })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let message = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "<no response body>".to_string());
+
+        return Err(match status.as_u16() {
+            401 | 403 => GeminiError::Auth(message),
+            429 => GeminiError::RateLimited,
+            code => GeminiError::Api { code, message },
+        });
+    }
+
+    let parsed: GenerateContentResponse = response
+        .json()
+        .await
+        .map_err(|e| GeminiError::BadResponse(e.to_string()))?;
+
+    parsed
+        .candidates
+        .into_iter()
+        .next()
+        .and_then(|c| c.content.parts.into_iter().next())
+        .map(|part| part.text)
+        .ok_or_else(|| GeminiError::BadResponse("no candidates in response".to_string()))
+}
+
 /// Call Gemini API with a prompt
-pub async fn call_gemini(prompt: &str) -> Result<String, String> {
-    // TODO: Implement Gemini API client logic
-    // - Load GEMINI_API_KEY from environment
-    // - Send prompt to Gemini API
-    // - Handle response and errors
-    Ok(format!("Response to prompt: {}", prompt))
+///
+/// Loads `GEMINI_API_KEY` from the environment, then retries
+/// [`generate_content`] with exponential backoff up to
+/// [`DEFAULT_MAX_ATTEMPTS`] times — but only for failures
+/// [`GeminiError::is_retryable`] says are worth retrying. A permanent
+/// failure (bad auth, a non-5xx API error, an unparseable response) returns
+/// immediately on the first attempt.
+pub async fn call_gemini(prompt: &str) -> Result<String, GeminiError> {
+    let api_key = std::env::var("GEMINI_API_KEY")
+        .map_err(|_| GeminiError::Auth("GEMINI_API_KEY not set".to_string()))?;
+
+    let client = reqwest::Client::new();
+
+    let mut last_err = None;
+    for attempt in 1..=DEFAULT_MAX_ATTEMPTS {
+        match generate_content(&client, &api_key, prompt, DEFAULT_REQUEST_TIMEOUT).await {
+            Ok(text) => return Ok(text),
+            Err(err) => {
+                if !err.is_retryable() || attempt == DEFAULT_MAX_ATTEMPTS {
+                    return Err(err);
+                }
+                let delay = RETRY_BACKOFF.next_delay(attempt, None);
+                tokio::time::sleep(delay).await;
+                last_err = Some(err);
+            }
+        }
+    }
+
+    // Unreachable in practice: the loop above always returns on the last
+    // attempt, but `last_err` keeps this exhaustive without a panic.
+    Err(last_err.unwrap_or(GeminiError::BadResponse("no attempts made".to_string())))
 }
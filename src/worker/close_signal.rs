@@ -0,0 +1,142 @@
+//! Shared "worker closed" signal
+//!
+//! Modeled on tower-buffer's `Closed`/`ServiceError`: when the worker task
+//! that drains the queue terminates (panics, exits, or is cancelled), every
+//! caller currently waiting on a dequeue/ack, and every caller that calls in
+//! afterward, should observe the *same* root-cause error instead of a
+//! generic timeout. `WorkerCloseSignal` is a cheaply-cloneable handle around
+//! a `tokio::sync::watch` channel carrying at most one terminal error.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// A shared slot that captures the first error that closes a worker, then
+/// hands a clone of that same `Arc` to every past and future observer
+#[derive(Clone)]
+pub struct WorkerCloseSignal {
+    tx: Arc<watch::Sender<Option<Arc<dyn StdError + Send + Sync>>>>,
+    rx: watch::Receiver<Option<Arc<dyn StdError + Send + Sync>>>,
+}
+
+impl WorkerCloseSignal {
+    /// Create a new, not-yet-closed signal
+    pub fn new() -> Self {
+        let (tx, rx) = watch::channel(None);
+        Self { tx: Arc::new(tx), rx }
+    }
+
+    /// Record the terminating error, if one hasn't already been recorded
+    ///
+    /// Only the first call has any effect; later calls are ignored so the
+    /// original root cause is preserved rather than overwritten by whatever
+    /// secondary error surfaces while the worker is unwinding. Uses
+    /// `send_if_modified` so the check-and-set is atomic: concurrent callers
+    /// racing here can't both observe `None` and both win.
+    pub fn close(&self, error: Arc<dyn StdError + Send + Sync>) {
+        self.tx.send_if_modified(|slot| {
+            if slot.is_some() {
+                return false;
+            }
+            *slot = Some(error);
+            true
+        });
+    }
+
+    /// Return the close error immediately if the worker has already closed
+    pub fn closed_error(&self) -> Option<Arc<dyn StdError + Send + Sync>> {
+        self.rx.borrow().clone()
+    }
+
+    /// Wait until the worker closes, then return the shared root-cause error
+    ///
+    /// Resolves immediately if the worker already closed before this call.
+    pub async fn wait_closed(&self) -> Arc<dyn StdError + Send + Sync> {
+        let mut rx = self.rx.clone();
+        loop {
+            if let Some(err) = rx.borrow().clone() {
+                return err;
+            }
+            if rx.changed().await.is_err() {
+                // The sender shares this signal's lifetime and is never
+                // dropped without closing first, but fall back to a generic
+                // error rather than hanging forever if that ever changes.
+                return Arc::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "worker closed without recording a cause",
+                ));
+            }
+        }
+    }
+}
+
+impl Default for WorkerCloseSignal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_wait_closed_resolves_after_close() {
+        let signal = WorkerCloseSignal::new();
+        let waiter = signal.clone();
+
+        let handle = tokio::spawn(async move { waiter.wait_closed().await });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        signal.close(Arc::new(io::Error::new(io::ErrorKind::Other, "worker panicked")));
+
+        let err = handle.await.unwrap();
+        assert_eq!(err.to_string(), "worker panicked");
+    }
+
+    #[test]
+    fn test_closed_error_is_none_before_close() {
+        let signal = WorkerCloseSignal::new();
+        assert!(signal.closed_error().is_none());
+    }
+
+    #[test]
+    fn test_close_is_idempotent_first_error_wins() {
+        let signal = WorkerCloseSignal::new();
+        signal.close(Arc::new(io::Error::new(io::ErrorKind::Other, "first")));
+        signal.close(Arc::new(io::Error::new(io::ErrorKind::Other, "second")));
+
+        let err = signal.closed_error().unwrap();
+        assert_eq!(err.to_string(), "first");
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_close_calls_agree_on_a_single_winner() {
+        let signal = WorkerCloseSignal::new();
+
+        let handles: Vec<_> = (0..32)
+            .map(|i| {
+                let signal = signal.clone();
+                tokio::spawn(async move {
+                    signal.close(Arc::new(io::Error::new(
+                        io::ErrorKind::Other,
+                        format!("cause-{}", i),
+                    )));
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        // Whichever `close` call actually won, every observer — this signal
+        // included — must agree on exactly one winner instead of two racing
+        // callers each believing their own error "won" because both saw
+        // `None` before either send.
+        let won = signal.closed_error().unwrap().to_string();
+        assert!(won.starts_with("cause-"));
+    }
+}
@@ -3,6 +3,16 @@
 //! This module provides functionality for worker processes that handle
 //! asynchronous job processing with retry logic and error handling.
 
+pub mod close_signal;
+pub mod dispatch;
+pub mod panic_guard;
+pub mod poll_timer;
 pub mod retry;
+pub mod watchdog;
 
+pub use close_signal::WorkerCloseSignal;
+pub use dispatch::{Dispatcher, JobError, JobProcessor, WorkerRuntime};
+pub use panic_guard::run_job_catching_unwind;
+pub use poll_timer::{PollTimer, WithPollTimer, DEFAULT_POLL_WARN_THRESHOLD};
 pub use retry::{RetryConfig, RetryPolicy};
+pub use watchdog::{JobTimer, WatchdogThresholds};
@@ -0,0 +1,82 @@
+//! Panic isolation for job execution
+//!
+//! A single buggy job shouldn't be able to take an entire worker thread
+//! down with it. `run_job_catching_unwind` wraps a job future in
+//! `catch_unwind`, so a panic inside job processing is converted into a
+//! [`WorkerError::Panicked`] the caller can handle like any other failure
+//! mode instead of unwinding past the worker loop.
+
+use crate::error::WorkerError;
+use futures_util::FutureExt;
+use std::any::Any;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+
+/// Run `job_future` to completion, catching any panic it raises and
+/// converting it into `WorkerError::Panicked` instead of letting it unwind
+/// into the worker loop
+///
+/// `job_id` is attached to the resulting error so panics can be correlated
+/// with the job that triggered them in logs and metrics.
+pub async fn run_job_catching_unwind<F, T>(job_id: &str, job_future: F) -> Result<T, WorkerError>
+where
+    F: Future<Output = Result<T, WorkerError>>,
+{
+    match AssertUnwindSafe(job_future).catch_unwind().await {
+        Ok(result) => result,
+        Err(panic_payload) => Err(WorkerError::Panicked {
+            job_id: job_id.to_string(),
+            message: panic_message(&panic_payload),
+        }),
+    }
+}
+
+/// Downcast a caught panic payload to the message it carries
+///
+/// Panics raised via `panic!("...")` or `.unwrap()` carry a `&str` or
+/// `String` payload; anything else (a custom `panic_any` payload) falls
+/// back to a generic message rather than failing to report the panic at all.
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "worker job panicked with a non-string payload".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_catches_panic() {
+        let result: Result<(), WorkerError> =
+            run_job_catching_unwind("job-1", async { panic!("boom") }).await;
+
+        match result {
+            Err(WorkerError::Panicked { job_id, message }) => {
+                assert_eq!(job_id, "job-1");
+                assert_eq!(message, "boom");
+            }
+            other => panic!("expected Panicked, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_passes_through_success() {
+        let result = run_job_catching_unwind("job-2", async { Ok::<_, WorkerError>(42) }).await;
+        assert_eq!(result.unwrap(), 42);
+    }
+
+    #[tokio::test]
+    async fn test_passes_through_error() {
+        let result: Result<(), WorkerError> = run_job_catching_unwind("job-3", async {
+            Err(WorkerError::InternalError("failed".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(WorkerError::InternalError(_))));
+    }
+}
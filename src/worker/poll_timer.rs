@@ -0,0 +1,128 @@
+//! Poll-timer instrumentation for diagnosing slow async operations
+//!
+//! Wraps a future so it accumulates real wall-clock time across however many
+//! times it's actually polled, and logs a structured warning the first time a
+//! single invocation's elapsed time crosses a threshold. Unlike
+//! [`crate::worker::watchdog::JobTimer`] (which times a whole job end-to-end
+//! across possibly many operations), [`PollTimer`] instruments one
+//! individual async call — e.g. a single `Queue::dequeue()` — so operators
+//! can tell "this one enqueue took 4 seconds" apart from "this job has been
+//! running for an hour across many operations."
+
+use crate::error::{ErrorCode, ErrorContext, ErrorSeverity};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+/// Default elapsed-time threshold past which [`PollTimer`] logs a warning
+pub const DEFAULT_POLL_WARN_THRESHOLD: Duration = Duration::from_millis(500);
+
+/// A future wrapped with poll-timer instrumentation; see the module docs
+pub struct PollTimer<F> {
+    inner: F,
+    name: &'static str,
+    threshold: Duration,
+    job_id: Option<String>,
+    started: Option<Instant>,
+    warned: bool,
+}
+
+impl<F> PollTimer<F> {
+    /// Attach a `job_id` to the warning this timer logs, if it fires
+    pub fn with_job_id(mut self, job_id: impl Into<String>) -> Self {
+        self.job_id = Some(job_id.into());
+        self
+    }
+
+    /// Override the default elapsed-time warning threshold
+    pub fn with_threshold(mut self, threshold: Duration) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<F: Future> Future for PollTimer<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Safety: `inner` is structurally pinned — it's never moved out of
+        // `self` and `PollTimer` is only ever observed through this pinned
+        // reference, so projecting a `Pin<&mut F>` out of it is sound.
+        let this = unsafe { self.get_unchecked_mut() };
+        let started = *this.started.get_or_insert_with(Instant::now);
+        let inner = unsafe { Pin::new_unchecked(&mut this.inner) };
+
+        let output = inner.poll(cx);
+
+        let elapsed = started.elapsed();
+        if !this.warned && elapsed >= this.threshold {
+            this.warned = true;
+
+            let mut context =
+                ErrorContext::new(ErrorCode::QueueTimeoutError, ErrorSeverity::Warning)
+                    .with_metadata("operation", this.name.to_string())
+                    .with_metadata("elapsed_ms", elapsed.as_millis().to_string());
+            if let Some(job_id) = &this.job_id {
+                context = context.with_job_id(job_id.clone());
+            }
+
+            println!(
+                "poll_timer warning: {}",
+                serde_json::to_string(&context).unwrap_or_else(|_| format!("{:?}", context))
+            );
+        }
+
+        output
+    }
+}
+
+/// Extension trait attaching poll-timer instrumentation to any future
+pub trait WithPollTimer: Future + Sized {
+    /// Wrap this future so it logs a warning if a single poll-to-completion
+    /// takes longer than [`DEFAULT_POLL_WARN_THRESHOLD`]
+    ///
+    /// `name` identifies the operation in the logged warning (e.g.
+    /// `"Queue::dequeue"`). Chain [`PollTimer::with_job_id`] /
+    /// [`PollTimer::with_threshold`] to customize further.
+    fn with_poll_timer(self, name: &'static str) -> PollTimer<Self> {
+        PollTimer {
+            inner: self,
+            name,
+            threshold: DEFAULT_POLL_WARN_THRESHOLD,
+            job_id: None,
+            started: None,
+            warned: false,
+        }
+    }
+}
+
+impl<F: Future> WithPollTimer for F {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fast_future_resolves_normally() {
+        let result = async { 42 }.with_poll_timer("fast_op").await;
+        assert_eq!(result, 42);
+    }
+
+    #[tokio::test]
+    async fn test_slow_future_crosses_threshold_without_panicking() {
+        tokio::time::sleep(Duration::from_millis(20))
+            .with_poll_timer("slow_op")
+            .with_threshold(Duration::from_millis(5))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_with_job_id_does_not_change_output() {
+        let result = async { "done" }
+            .with_poll_timer("op_with_job")
+            .with_job_id("job-123")
+            .await;
+        assert_eq!(result, "done");
+    }
+}
@@ -4,8 +4,10 @@
 //! to handle temporary failures in job processing.
 
 use crate::queue::QueuedJob;
+use chrono::Utc;
 use std::error::Error;
 use std::fmt;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 /// Error types for retry policy
@@ -41,6 +43,15 @@ pub struct RetryConfig {
     pub backoff_multiplier: f64,
     /// Whether to add jitter to delays
     pub jitter: bool,
+    /// Total wall-clock budget for retrying a single job, measured from
+    /// `QueuedJob.claimed_at`. `None` means no deadline — retries are bounded
+    /// only by `max_attempts` as before. When set, `next_attempt_delay`
+    /// returns `None` (routing the job to DLQ) once the next delay would
+    /// push the job past this budget, even if attempts remain.
+    pub max_elapsed: Option<Duration>,
+    /// Growth curve applied to the base delay across attempts. Defaults to
+    /// `Exponential`, preserving the policy's original behavior.
+    pub backoff_kind: BackoffKind,
 }
 
 impl Default for RetryConfig {
@@ -51,14 +62,362 @@ impl Default for RetryConfig {
             max_delay: Duration::from_millis(30000),
             backoff_multiplier: 2.0,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         }
     }
 }
 
-/// Retry policy for handling job failures
+/// Growth curve for the computed (pre-jitter) retry delay
+///
+/// `calculate_delay` dispatches on this before applying `jitter_strategy`,
+/// so any kind composes with any jitter strategy. All kinds are still
+/// clamped to `max_delay`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BackoffKind {
+    /// Always `base_delay`, regardless of attempt number
+    Fixed,
+    /// `base_delay * attempt`
+    Linear,
+    /// `base_delay * backoff_multiplier ^ (attempt - 1)` — the original,
+    /// and still the default, behavior
+    #[default]
+    Exponential,
+    /// `delay[n] = delay[n-1] + delay[n-2]`, starting from `base_delay` for
+    /// both of the first two attempts — gentler growth than exponential,
+    /// packing more retry attempts into the same overall budget
+    Fibonacci,
+}
+
+/// Maximum retry attempts policy
+///
+/// Lets callers opt a job out of the usual attempt cap (e.g. for
+/// best-effort background work that should keep retrying forever) without
+/// overloading `max_attempts == 0`, which is rejected as invalid config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaxRetries {
+    /// Retry forever; the job never exhausts attempts
+    Infinite,
+    /// Retry until `attempts` reaches this count
+    Count(u32),
+}
+
+/// Why a job is being routed to the Dead Letter Queue instead of retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadLetterReason {
+    /// `attempts` reached `config.max_attempts` (or the next delay would
+    /// push the job past `config.max_elapsed`) without the job succeeding
+    MaxAttemptsExceeded,
+    /// The classifier judged the failure unrecoverable — retrying would
+    /// never succeed no matter how many attempts remain
+    PermanentError,
+    /// The job's payload couldn't be deserialized at all, so there's no
+    /// well-formed job to retry in the first place
+    MalformedPayload,
+}
+
+/// What a worker should do with a job after [`RetryPolicy::decide`]
+///
+/// Replaces a bare `Option<Duration>` with a richer outcome so a worker can
+/// transition the job to [`crate::queue::JobStatus::DLQ`] with a structured
+/// reason instead of just being told "don't retry" and having to guess why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryDecision {
+    /// Wait `delay`, then retry the job
+    Retry { delay: Duration },
+    /// Stop retrying and move the job to the Dead Letter Queue
+    DeadLetter { reason: DeadLetterReason },
+    /// Stop retrying, but don't dead-letter either — e.g. a shared
+    /// [`RetryTokenBucket`] is out of tokens, a system-wide condition that
+    /// isn't this job's fault
+    Drop,
+}
+
+/// How a [`RetryClassifier`] categorizes a failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A failure expected to resolve itself on its own (network blip,
+    /// connection reset) — safe to retry on the usual backoff schedule.
+    Transient,
+    /// The dependency is asking us to slow down (e.g. HTTP 429) — safe to
+    /// retry, but a caller may want to back off more aggressively. An
+    /// upstream-provided `retry_after` hint (e.g. parsed from a
+    /// `Retry-After` header), if present, takes priority over the computed
+    /// backoff curve in [`RetryPolicy::next_attempt_delay_with_cost`].
+    Throttling { retry_after: Option<Duration> },
+    /// A failure that will never succeed no matter how many times it's
+    /// retried (e.g. a 400-class validation error, malformed job payload) —
+    /// `should_retry` short-circuits these straight to failure instead of
+    /// burning the full attempt budget.
+    Permanent,
+}
+
+/// Classifies an error into an [`ErrorKind`] so `RetryPolicy` can decide
+/// whether retrying is even worth attempting
+///
+/// The default classifier ([`AlwaysTransientClassifier`]) treats every
+/// error as transient, preserving the policy's original behavior of
+/// retrying everything up to `max_attempts`.
+pub trait RetryClassifier: Send + Sync {
+    /// Categorize `err` to decide whether it's worth retrying
+    fn classify(&self, err: &(dyn Error + Send + Sync)) -> ErrorKind;
+}
+
+/// Default classifier: every error is treated as transient
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysTransientClassifier;
+
+impl RetryClassifier for AlwaysTransientClassifier {
+    fn classify(&self, _err: &(dyn Error + Send + Sync)) -> ErrorKind {
+        ErrorKind::Transient
+    }
+}
+
+/// How randomization is applied to a computed backoff delay
+///
+/// Replaces the old plain `bool` (`RetryConfig::jitter`) with the specific
+/// strategies engines like AWS's SDKs offer, since "on vs. off" hides a real
+/// choice: full jitter spreads retries the most, equal jitter keeps delays
+/// closer to the computed curve, and decorrelated jitter spreads retries
+/// out further with each attempt rather than resetting every time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JitterStrategy {
+    /// No randomization; always use the exact computed delay
+    None,
+    /// Uniform random value in `[0, computed_delay]`
+    Full,
+    /// `computed_delay / 2 + random(0, computed_delay / 2)`
+    Equal,
+    /// AWS-style decorrelated jitter: `random(base_delay, 3 * previous_delay)`,
+    /// capped at `max_delay`
+    Decorrelated,
+}
+
+impl Default for JitterStrategy {
+    fn default() -> Self {
+        JitterStrategy::Full
+    }
+}
+
+/// Back-compat bridge from the old `RetryConfig::jitter` bool: `true` maps
+/// to the previous default behavior (`Full`), `false` to no jitter at all.
+impl From<bool> for JitterStrategy {
+    fn from(jitter: bool) -> Self {
+        if jitter {
+            JitterStrategy::Full
+        } else {
+            JitterStrategy::None
+        }
+    }
+}
+
+/// Default capacity a [`RetryTokenBucket`] starts with when not configured
+/// explicitly
+pub const DEFAULT_TOKEN_BUCKET_CAPACITY: u32 = 500;
+/// Token cost to acquire from a [`RetryTokenBucket`] for an ordinary retry
+pub const RETRY_COST_DEFAULT: u32 = 5;
+/// Token cost to acquire from a [`RetryTokenBucket`] when retrying a timeout
+/// or connection-class failure — these are the ones most likely to be
+/// symptoms of a dependency-wide outage, so they drain the bucket faster
+pub const RETRY_COST_TIMEOUT_OR_CONNECTION: u32 = 10;
+/// Token cost to acquire from a [`RetryTokenBucket`] when retrying a
+/// throttling response (e.g. HTTP 429)
+pub const RETRY_COST_THROTTLE: u32 = 1;
+/// Tokens returned to a [`RetryTokenBucket`] when a job succeeds
+pub const RETRY_SUCCESS_REFILL: u32 = 1;
+
+/// A shared retry budget that caps the system-wide retry rate
+///
+/// Modeled on smithy-rs's standard retry orchestrator: every retry attempt
+/// tries to acquire a cost from the bucket, and every successful job
+/// returns a small amount back. When a dependency has an outage, the bucket
+/// drains and `try_acquire` starts failing, so jobs route straight to DLQ
+/// instead of every failing job independently burning its full backoff
+/// schedule and hammering the dependency once it recovers.
 #[derive(Debug)]
+pub struct RetryTokenBucket {
+    capacity: u32,
+    tokens: Mutex<u32>,
+}
+
+impl RetryTokenBucket {
+    /// Create a bucket starting out full, holding at most `capacity` tokens
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            capacity,
+            tokens: Mutex::new(capacity),
+        }
+    }
+
+    /// Try to take `cost` tokens from the bucket
+    ///
+    /// Returns `true` and deducts the tokens if enough are available,
+    /// `false` (leaving the bucket untouched) otherwise.
+    pub fn try_acquire(&self, cost: u32) -> bool {
+        let mut tokens = self.tokens.lock().unwrap();
+        if *tokens >= cost {
+            *tokens -= cost;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Alias for [`Self::try_acquire`] matching the worker-facing retry
+    /// vocabulary (`try_acquire_retry`/`record_success`)
+    pub fn try_acquire_retry(&self, cost: u32) -> bool {
+        self.try_acquire(cost)
+    }
+
+    /// Return `amount` tokens to the bucket, capped at `capacity`
+    pub fn release(&self, amount: u32) {
+        let mut tokens = self.tokens.lock().unwrap();
+        *tokens = tokens.saturating_add(amount).min(self.capacity);
+    }
+
+    /// Refill [`RETRY_SUCCESS_REFILL`] tokens after a job completes
+    /// successfully — the worker-facing counterpart to
+    /// [`Self::try_acquire_retry`]
+    pub fn record_success(&self) {
+        self.release(RETRY_SUCCESS_REFILL);
+    }
+
+    /// Current token count, for metrics
+    pub fn available_tokens(&self) -> u32 {
+        *self.tokens.lock().unwrap()
+    }
+}
+
+impl Default for RetryTokenBucket {
+    /// A bucket at [`DEFAULT_TOKEN_BUCKET_CAPACITY`]
+    fn default() -> Self {
+        Self::new(DEFAULT_TOKEN_BUCKET_CAPACITY)
+    }
+}
+
+/// Retry policy for handling job failures
 pub struct RetryPolicy {
     pub config: RetryConfig,
+    /// Seed for deterministic jitter, set via [`RetryPolicy::new_with_seed`].
+    /// `None` means jitter uses the thread-local RNG as before.
+    seed: Option<u64>,
+    /// Classifies failures as transient/throttling/permanent so permanent
+    /// failures can short-circuit straight to DLQ. Defaults to
+    /// [`AlwaysTransientClassifier`], set via
+    /// [`RetryPolicy::new_with_classifier`].
+    classifier: Box<dyn RetryClassifier>,
+    /// Shared retry budget, set via [`RetryPolicy::new_with_token_bucket`].
+    /// `None` means retries are never throttled by a shared budget.
+    token_bucket: Option<Arc<RetryTokenBucket>>,
+    /// Jitter strategy applied to computed delays. Defaults to
+    /// `JitterStrategy::from(config.jitter)`; set explicitly via
+    /// [`RetryPolicy::new_with_jitter_strategy`].
+    jitter_strategy: JitterStrategy,
+    /// Pluggable backoff curve, set via
+    /// [`RetryPolicy::new_with_backoff_strategy`]. `None` keeps the
+    /// original behavior of computing the curve from `config.backoff_kind`.
+    /// When set, this replaces that computation entirely (jitter_strategy
+    /// still applies on top, except `JitterStrategy::Decorrelated`, which
+    /// already fully determines its own curve and takes precedence).
+    backoff_strategy: Option<Box<dyn BackoffStrategy>>,
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("config", &self.config)
+            .field("seed", &self.seed)
+            .field("token_bucket", &self.token_bucket)
+            .field("jitter_strategy", &self.jitter_strategy)
+            .finish()
+    }
+}
+
+/// Computes a backoff delay for a given attempt, independent of any
+/// `RetryPolicy`
+///
+/// [`RetryPolicy`]'s built-in `config.backoff_kind` covers the common
+/// curves inline; this trait exists for callers (e.g. distinct `JobType`s)
+/// that want a custom curve, or want to unit-test the curve math in
+/// isolation without constructing a full `RetryConfig`. Set via
+/// [`RetryPolicy::new_with_backoff_strategy`].
+pub trait BackoffStrategy: Send + Sync {
+    /// Compute the (pre-jitter, pre-cap) delay for `attempt` (1-based).
+    /// `prev_delay` is the delay returned for the previous attempt, if any
+    /// — strategies that don't need it (e.g. [`ExponentialBackoff`],
+    /// [`FixedBackoff`]) simply ignore it.
+    fn next_delay(&self, attempt: u32, prev_delay: Option<Duration>) -> Duration;
+}
+
+/// `base_delay * multiplier ^ (attempt - 1)`, capped at `max_delay`
+#[derive(Debug, Clone, Copy)]
+pub struct ExponentialBackoff {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub multiplier: f64,
+}
+
+impl BackoffStrategy for ExponentialBackoff {
+    fn next_delay(&self, attempt: u32, _prev_delay: Option<Duration>) -> Duration {
+        let base_millis = self.base_delay.as_millis() as f64;
+        let exponent = attempt.max(1).saturating_sub(1);
+        let millis = base_millis * self.multiplier.powi(exponent as i32);
+        Duration::from_millis(millis as u64).min(self.max_delay)
+    }
+}
+
+/// Always `delay`, regardless of attempt number
+#[derive(Debug, Clone, Copy)]
+pub struct FixedBackoff {
+    pub delay: Duration,
+}
+
+impl BackoffStrategy for FixedBackoff {
+    fn next_delay(&self, _attempt: u32, _prev_delay: Option<Duration>) -> Duration {
+        self.delay
+    }
+}
+
+/// AWS-style decorrelated jitter as a standalone [`BackoffStrategy`]:
+/// `random(base_delay, 3 * prev_delay)`, capped at `max_delay` and floored
+/// at `base_delay`. `prev_delay` falls back to `base_delay` on the first
+/// attempt.
+#[derive(Debug, Clone, Copy)]
+pub struct DecorrelatedBackoff {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl BackoffStrategy for DecorrelatedBackoff {
+    fn next_delay(&self, attempt: u32, prev_delay: Option<Duration>) -> Duration {
+        use rand::Rng;
+
+        let base_millis = self.base_delay.as_millis() as f64;
+        let max_millis = self.max_delay.as_millis() as f64;
+        let prev_millis = if attempt <= 1 {
+            base_millis
+        } else {
+            prev_delay.unwrap_or(self.base_delay).as_millis() as f64
+        };
+        let upper = (prev_millis * 3.0).max(base_millis).min(max_millis);
+        let millis = rand::thread_rng().gen_range(base_millis..=upper);
+        Duration::from_millis(millis as u64)
+    }
+}
+
+/// `delay[n] = delay[n-1] + delay[n-2]`, both seeded with `base_millis` for
+/// the first two attempts, matching [`BackoffKind::Fibonacci`]
+fn fibonacci_delay_millis(base_millis: f64, attempt: u32) -> f64 {
+    if attempt <= 1 {
+        return base_millis;
+    }
+    let (mut prev, mut curr) = (base_millis, base_millis);
+    for _ in 3..=attempt {
+        let next = prev + curr;
+        prev = curr;
+        curr = next;
+    }
+    curr
 }
 
 impl RetryPolicy {
@@ -78,7 +437,105 @@ impl RetryPolicy {
     /// ```
     pub fn new(config: RetryConfig) -> Result<Self, RetryError> {
         Self::validate_config(&config)?;
-        Ok(Self { config })
+        Ok(Self {
+            jitter_strategy: JitterStrategy::from(config.jitter),
+            config,
+            seed: None,
+            classifier: Box::new(AlwaysTransientClassifier),
+            token_bucket: None,
+            backoff_strategy: None,
+        })
+    }
+
+    /// Create a retry policy whose jitter is derived from a fixed seed
+    ///
+    /// Useful in tests that need reproducible delay sequences: the same
+    /// seed and attempt number always produce the same jittered delay.
+    pub fn new_with_seed(config: RetryConfig, seed: u64) -> Result<Self, RetryError> {
+        Self::validate_config(&config)?;
+        Ok(Self {
+            jitter_strategy: JitterStrategy::from(config.jitter),
+            config,
+            seed: Some(seed),
+            classifier: Box::new(AlwaysTransientClassifier),
+            token_bucket: None,
+            backoff_strategy: None,
+        })
+    }
+
+    /// Create a retry policy that uses `classifier` to short-circuit
+    /// permanent failures instead of retrying them up to `max_attempts`
+    pub fn new_with_classifier(
+        config: RetryConfig,
+        classifier: Box<dyn RetryClassifier>,
+    ) -> Result<Self, RetryError> {
+        Self::validate_config(&config)?;
+        Ok(Self {
+            jitter_strategy: JitterStrategy::from(config.jitter),
+            config,
+            seed: None,
+            classifier,
+            token_bucket: None,
+            backoff_strategy: None,
+        })
+    }
+
+    /// Create a retry policy that acquires tokens from a shared
+    /// [`RetryTokenBucket`] before allowing a retry
+    ///
+    /// Multiple policies can share one `Arc<RetryTokenBucket>` so the whole
+    /// worker pool draws against a single system-wide retry budget.
+    pub fn new_with_token_bucket(
+        config: RetryConfig,
+        token_bucket: Arc<RetryTokenBucket>,
+    ) -> Result<Self, RetryError> {
+        Self::validate_config(&config)?;
+        Ok(Self {
+            jitter_strategy: JitterStrategy::from(config.jitter),
+            config,
+            seed: None,
+            classifier: Box::new(AlwaysTransientClassifier),
+            token_bucket: Some(token_bucket),
+            backoff_strategy: None,
+        })
+    }
+
+    /// Create a retry policy that applies an explicit [`JitterStrategy`]
+    /// instead of the one implied by `config.jitter`
+    pub fn new_with_jitter_strategy(
+        config: RetryConfig,
+        jitter_strategy: JitterStrategy,
+    ) -> Result<Self, RetryError> {
+        Self::validate_config(&config)?;
+        Ok(Self {
+            config,
+            seed: None,
+            classifier: Box::new(AlwaysTransientClassifier),
+            token_bucket: None,
+            jitter_strategy,
+            backoff_strategy: None,
+        })
+    }
+
+    /// Create a retry policy whose backoff curve is computed by `strategy`
+    /// instead of `config.backoff_kind`
+    ///
+    /// Lets different callers (e.g. distinct `JobType`s) plug in their own
+    /// [`BackoffStrategy`] while sharing the rest of the policy engine
+    /// (classification, token bucket, jitter, `max_elapsed`).
+    pub fn new_with_backoff_strategy(
+        config: RetryConfig,
+        strategy: Box<dyn BackoffStrategy>,
+    ) -> Result<Self, RetryError> {
+        Self::validate_config(&config)?;
+        Ok(Self {
+            jitter_strategy: JitterStrategy::from(config.jitter),
+            config,
+            seed: None,
+            classifier: Box::new(AlwaysTransientClassifier),
+            token_bucket: None,
+            backoff_strategy: Some(strategy),
+        })
     }
 
     /// Validate retry configuration parameters
@@ -103,19 +560,34 @@ impl RetryPolicy {
                 "backoff_multiplier must be > 1.0".to_string(),
             ));
         }
+        if let Some(max_elapsed) = config.max_elapsed {
+            if max_elapsed < config.base_delay {
+                return Err(RetryError::InvalidConfig(
+                    "max_elapsed must be >= base_delay".to_string(),
+                ));
+            }
+        }
         Ok(())
     }
 
     /// Determine if a job should be retried based on attempts and error
-    pub fn should_retry(&self, attempts: u32, _error: &(dyn Error + Send + Sync)) -> bool {
+    ///
+    /// Permanent failures (per `self.classifier`) short-circuit to `false`
+    /// regardless of how many attempts remain, since retrying them would
+    /// only burn the attempt budget without ever succeeding.
+    pub fn should_retry(&self, attempts: u32, error: &(dyn Error + Send + Sync)) -> bool {
+        if self.classifier.classify(error) == ErrorKind::Permanent {
+            return false;
+        }
         attempts < self.config.max_attempts
     }
 
     /// Calculate delay for the next retry attempt
     ///
-    /// Uses exponential backoff: delay = base_delay * (multiplier ^ (attempts-1))
-    /// Capped at max_delay to prevent excessive delays
-    /// Applies jitter if enabled to prevent thundering herd
+    /// The growth curve is chosen by `self.config.backoff_kind` (exponential
+    /// by default, matching the original behavior). Capped at max_delay to
+    /// prevent excessive delays. Applies `self.jitter_strategy` to prevent
+    /// thundering herd.
     ///
     /// # Arguments
     /// * `attempts` - Current attempt number (1-based)
@@ -123,14 +595,22 @@ impl RetryPolicy {
     /// # Returns
     /// * `Duration` - Calculated delay before next retry
     pub fn calculate_delay(&self, attempts: u32) -> Duration {
-        let base_delay = self.config.base_delay;
-
-        // Calculate exponential backoff with overflow protection
-        let exponent = attempts.saturating_sub(1);
-        let multiplier = self.config.backoff_multiplier.powi(exponent as i32);
+        // Decorrelated jitter ignores `backoff_kind`'s growth curve entirely
+        // (it's not exponential-based), and each attempt's delay depends on
+        // the previous one, so it's computed by replaying the chain from
+        // attempt 1 rather than from `uncapped_delay_millis`. This takes
+        // precedence over `self.backoff_strategy`, since both fully
+        // determine their own curve.
+        if self.jitter_strategy == JitterStrategy::Decorrelated {
+            return self.decorrelated_delay_for_attempt(attempts);
+        }
 
-        // Use f64 for calculation to handle large numbers, then convert back
-        let delay_millis = base_delay.as_millis() as f64 * multiplier;
+        let delay_millis = if let Some(strategy) = &self.backoff_strategy {
+            self.replay_strategy_chain(strategy.as_ref(), attempts)
+                .as_millis() as f64
+        } else {
+            self.uncapped_delay_millis(attempts)
+        };
 
         // Cap at max_delay to prevent excessive delays
         let capped_delay_millis = delay_millis.min(self.config.max_delay.as_millis() as f64);
@@ -138,10 +618,58 @@ impl RetryPolicy {
         // Convert to Duration, ensuring no overflow
         let delay = Duration::from_millis(capped_delay_millis as u64);
 
-        if self.config.jitter {
-            self.add_jitter(delay)
-        } else {
-            delay
+        match self.jitter_strategy {
+            JitterStrategy::None => delay,
+            JitterStrategy::Full => self.add_jitter(delay),
+            JitterStrategy::Equal => self.add_equal_jitter(delay),
+            JitterStrategy::Decorrelated => unreachable!("handled above"),
+        }
+    }
+
+    /// Replay the decorrelated-jitter chain from attempt 1 up to `attempts`,
+    /// since each attempt's delay is drawn relative to the previous one
+    /// (seeded with `base_delay` for the first attempt) rather than computed
+    /// independently
+    fn decorrelated_delay_for_attempt(&self, attempts: u32) -> Duration {
+        let target = attempts.max(1);
+        let mut previous = self.config.base_delay;
+        let mut current = previous;
+        for attempt in 1..=target {
+            current = self.calculate_delay_decorrelated(attempt, previous);
+            previous = current;
+        }
+        current
+    }
+
+    /// Replay `strategy`'s chain from attempt 1 up to `attempts`, passing
+    /// each step's result as the next step's `prev_delay` — mirrors
+    /// [`Self::decorrelated_delay_for_attempt`], since `BackoffStrategy`
+    /// implementations may also depend on the previous delay
+    fn replay_strategy_chain(&self, strategy: &dyn BackoffStrategy, attempts: u32) -> Duration {
+        let target = attempts.max(1);
+        let mut prev_delay: Option<Duration> = None;
+        let mut current = Duration::from_millis(0);
+        for attempt in 1..=target {
+            current = strategy.next_delay(attempt, prev_delay);
+            prev_delay = Some(current);
+        }
+        current
+    }
+
+    /// Compute the pre-jitter, pre-cap delay in milliseconds for `attempts`
+    /// according to `self.config.backoff_kind`
+    fn uncapped_delay_millis(&self, attempts: u32) -> f64 {
+        let base_millis = self.config.base_delay.as_millis() as f64;
+        let attempt = attempts.max(1);
+
+        match self.config.backoff_kind {
+            BackoffKind::Fixed => base_millis,
+            BackoffKind::Linear => base_millis * attempt as f64,
+            BackoffKind::Exponential => {
+                let exponent = attempt.saturating_sub(1);
+                base_millis * self.config.backoff_multiplier.powi(exponent as i32)
+            }
+            BackoffKind::Fibonacci => fibonacci_delay_millis(base_millis, attempt),
         }
     }
 
@@ -149,11 +677,23 @@ impl RetryPolicy {
     ///
     /// Implements full jitter strategy: random delay between 0 and calculated delay
     /// This prevents multiple workers from retrying simultaneously (thundering herd)
+    ///
+    /// When the policy was built with [`RetryPolicy::new_with_seed`], the
+    /// random value is drawn from a seed derived from the attempt's delay so
+    /// repeated calls with the same inputs are reproducible.
     fn add_jitter(&self, delay: Duration) -> Duration {
         use rand::Rng;
 
-        // Full jitter: random value between 0 and delay
-        let jittered_millis = rand::thread_rng().gen_range(0.0..=delay.as_millis() as f64);
+        let jittered_millis = match self.seed {
+            Some(seed) => {
+                use rand::rngs::StdRng;
+                use rand::SeedableRng;
+
+                let mut rng = StdRng::seed_from_u64(seed ^ delay.as_millis() as u64);
+                rng.gen_range(0.0..=delay.as_millis() as f64)
+            }
+            None => rand::thread_rng().gen_range(0.0..=delay.as_millis() as f64),
+        };
 
         // Ensure minimum delay of at least base_delay / 4 to prevent too rapid retries
         let min_delay = (self.config.base_delay.as_millis() as f64 * 0.25).max(1.0);
@@ -162,16 +702,206 @@ impl RetryPolicy {
         Duration::from_millis(final_delay as u64)
     }
 
+    /// Add jitter using the equal jitter strategy
+    ///
+    /// Half the computed delay is kept fixed and a random value in
+    /// `[0, computed_delay / 2]` is added on top, so delays stay closer to
+    /// the exponential curve than full jitter while still avoiding exact
+    /// thundering-herd collisions.
+    fn add_equal_jitter(&self, delay: Duration) -> Duration {
+        use rand::Rng;
+
+        let half_millis = delay.as_millis() as f64 / 2.0;
+
+        let random_part = match self.seed {
+            Some(seed) => {
+                use rand::rngs::StdRng;
+                use rand::SeedableRng;
+
+                let mut rng = StdRng::seed_from_u64(seed ^ delay.as_millis() as u64 ^ 0x51A5);
+                rng.gen_range(0.0..=half_millis)
+            }
+            None => rand::thread_rng().gen_range(0.0..=half_millis),
+        };
+
+        Duration::from_millis((half_millis + random_part) as u64)
+    }
+
+    /// Compute the backoff delay for a given attempt number
+    ///
+    /// Alias for [`RetryPolicy::calculate_delay`] with a name matching the
+    /// rest of the retry vocabulary (`should_retry`, `next_attempt_delay`).
+    pub fn compute_backoff(&self, attempt: u32) -> Duration {
+        self.calculate_delay(attempt)
+    }
+
+    /// Calculate delay using decorrelated jitter
+    ///
+    /// Implements the AWS "decorrelated jitter" strategy: each delay is a
+    /// random value between `base_delay` and `3 * previous_delay`, capped at
+    /// `max_delay`. Unlike full jitter, this spreads out retries over a
+    /// widening range as attempts increase while still bounding burstiness,
+    /// since each delay is correlated with the last rather than independently
+    /// reset from the exponential curve every time.
+    ///
+    /// # Arguments
+    /// * `attempt` - Current attempt number (1-based)
+    /// * `previous_delay` - The delay returned by the prior call for this job;
+    ///   ignored on the first attempt, where `base_delay` is used instead
+    pub fn calculate_delay_decorrelated(&self, attempt: u32, previous_delay: Duration) -> Duration {
+        use rand::Rng;
+
+        let base_millis = self.config.base_delay.as_millis() as f64;
+        let max_millis = self.config.max_delay.as_millis() as f64;
+
+        let prev_millis = if attempt <= 1 {
+            base_millis
+        } else {
+            previous_delay.as_millis() as f64
+        };
+
+        let upper = (prev_millis * 3.0).max(base_millis).min(max_millis);
+
+        let delay_millis = match self.seed {
+            Some(seed) => {
+                use rand::rngs::StdRng;
+                use rand::SeedableRng;
+
+                let mut rng = StdRng::seed_from_u64(seed ^ attempt as u64);
+                rng.gen_range(base_millis..=upper)
+            }
+            None => rand::thread_rng().gen_range(base_millis..=upper),
+        };
+
+        Duration::from_millis(delay_millis as u64)
+    }
+
+    /// Determine if a job should be retried under an explicit [`MaxRetries`]
+    /// policy, independent of `self.config.max_attempts`
+    ///
+    /// Lets a caller opt a specific job out of the policy's configured cap
+    /// (e.g. `MaxRetries::Infinite` for best-effort background work) without
+    /// constructing a whole new `RetryPolicy`.
+    pub fn should_retry_for(&self, attempts: u32, max_retries: MaxRetries) -> bool {
+        match max_retries {
+            MaxRetries::Infinite => true,
+            MaxRetries::Count(max) => attempts < max,
+        }
+    }
+
     /// Get delay for next attempt, or None if max attempts reached
+    ///
+    /// When the policy has a shared [`RetryTokenBucket`] (set via
+    /// [`RetryPolicy::new_with_token_bucket`]), this also charges it
+    /// [`RETRY_COST_DEFAULT`] tokens; see
+    /// [`RetryPolicy::next_attempt_delay_with_cost`] to charge a different
+    /// cost (e.g. [`RETRY_COST_TIMEOUT_OR_CONNECTION`] for a timeout/
+    /// connection failure, or [`RETRY_COST_THROTTLE`] for a throttling
+    /// response).
     pub fn next_attempt_delay(
         &self,
         job: &QueuedJob,
         error: &(dyn Error + Send + Sync),
     ) -> Option<Duration> {
-        if self.should_retry(job.attempts, error) {
-            Some(self.calculate_delay(job.attempts + 1))
-        } else {
-            None
+        self.next_attempt_delay_with_cost(job, error, RETRY_COST_DEFAULT)
+    }
+
+    /// Get delay for next attempt, charging `cost` tokens from the shared
+    /// token bucket (if configured) instead of retrying
+    ///
+    /// Returns `None` — sending the job to DLQ immediately rather than
+    /// retrying — when any of the following holds: `should_retry` says no,
+    /// the token bucket doesn't have `cost` tokens available (retrying
+    /// during a dependency outage only makes the outage worse), or
+    /// `config.max_elapsed` is set and the next delay would push the job's
+    /// total elapsed time (measured from `job.claimed_at`) past that budget.
+    ///
+    /// When `self.classifier` classifies `error` as
+    /// [`ErrorKind::Throttling`] with a `retry_after` hint, that hint (capped
+    /// at `config.max_delay`) is returned directly instead of the computed
+    /// backoff curve — the dependency told us exactly how long to wait, so
+    /// there's no reason to guess.
+    pub fn next_attempt_delay_with_cost(
+        &self,
+        job: &QueuedJob,
+        error: &(dyn Error + Send + Sync),
+        cost: u32,
+    ) -> Option<Duration> {
+        match self.decide_with_cost(job, error, cost) {
+            RetryDecision::Retry { delay } => Some(delay),
+            RetryDecision::DeadLetter { .. } | RetryDecision::Drop => None,
+        }
+    }
+
+    /// Decide what should happen to `job` after `error`, charging
+    /// [`RETRY_COST_DEFAULT`] tokens from the shared token bucket (if
+    /// configured); see [`Self::decide_with_cost`] to charge a different
+    /// cost
+    pub fn decide(&self, job: &QueuedJob, error: &(dyn Error + Send + Sync)) -> RetryDecision {
+        self.decide_with_cost(job, error, RETRY_COST_DEFAULT)
+    }
+
+    /// Decide what should happen to `job` after `error`, charging `cost`
+    /// tokens from the shared token bucket (if configured)
+    ///
+    /// Returns [`RetryDecision::DeadLetter`] when `self.classifier`
+    /// classifies `error` as [`ErrorKind::Permanent`], when `job.attempts`
+    /// has reached `config.max_attempts`, or when `config.max_elapsed` is
+    /// set and the next delay would push the job's total elapsed time
+    /// (measured from `job.claimed_at`) past that budget. Returns
+    /// [`RetryDecision::Drop`] when a shared [`RetryTokenBucket`] doesn't
+    /// have `cost` tokens available — a system-wide throttle isn't the
+    /// job's fault, so it's neither retried immediately nor dead-lettered.
+    /// Otherwise returns [`RetryDecision::Retry`] with the delay to wait,
+    /// honoring an [`ErrorKind::Throttling`] `retry_after` hint (capped at
+    /// `config.max_delay`) over the computed backoff curve when present.
+    pub fn decide_with_cost(
+        &self,
+        job: &QueuedJob,
+        error: &(dyn Error + Send + Sync),
+        cost: u32,
+    ) -> RetryDecision {
+        let classification = self.classifier.classify(error);
+        if classification == ErrorKind::Permanent {
+            return RetryDecision::DeadLetter {
+                reason: DeadLetterReason::PermanentError,
+            };
+        }
+        if job.attempts >= self.config.max_attempts {
+            return RetryDecision::DeadLetter {
+                reason: DeadLetterReason::MaxAttemptsExceeded,
+            };
+        }
+        if let Some(bucket) = &self.token_bucket {
+            if !bucket.try_acquire(cost) {
+                return RetryDecision::Drop;
+            }
+        }
+        let delay = match classification {
+            ErrorKind::Throttling {
+                retry_after: Some(hint),
+            } => hint.min(self.config.max_delay),
+            _ => self.calculate_delay(job.attempts + 1),
+        };
+        if let Some(max_elapsed) = self.config.max_elapsed {
+            let elapsed = Utc::now()
+                .signed_duration_since(job.claimed_at)
+                .to_std()
+                .unwrap_or(Duration::from_secs(0));
+            if elapsed + delay > max_elapsed {
+                return RetryDecision::DeadLetter {
+                    reason: DeadLetterReason::MaxAttemptsExceeded,
+                };
+            }
+        }
+        RetryDecision::Retry { delay }
+    }
+
+    /// Return a small amount of tokens to the shared token bucket (if
+    /// configured) after a job succeeds
+    pub fn record_success(&self) {
+        if let Some(bucket) = &self.token_bucket {
+            bucket.release(RETRY_SUCCESS_REFILL);
         }
     }
 }
@@ -197,6 +927,8 @@ mod tests {
                 dedupe_key: None,
                 trace_id: None,
                 created_at: Utc::now(),
+                scheduled_at: None,
+                priority: 0,
                 metadata: json!({}),
             },
             attempts,
@@ -216,6 +948,8 @@ mod tests {
             max_delay: Duration::from_millis(5000),
             backoff_multiplier: 2.0,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config);
@@ -231,6 +965,8 @@ mod tests {
                 max_delay: Duration::from_millis(5000),
                 backoff_multiplier: 2.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
             RetryConfig {
                 max_attempts: 3,
@@ -238,6 +974,8 @@ mod tests {
                 max_delay: Duration::from_millis(5000),
                 backoff_multiplier: 2.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
             RetryConfig {
                 max_attempts: 3,
@@ -245,6 +983,8 @@ mod tests {
                 max_delay: Duration::from_millis(50),
                 backoff_multiplier: 2.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
             RetryConfig {
                 max_attempts: 3,
@@ -252,6 +992,8 @@ mod tests {
                 max_delay: Duration::from_millis(5000),
                 backoff_multiplier: 1.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
         ];
 
@@ -280,6 +1022,8 @@ mod tests {
             max_delay: Duration::from_millis(5000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -307,6 +1051,8 @@ mod tests {
             max_delay: Duration::from_millis(3000),
             backoff_multiplier: 3.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let max_delay = config.max_delay; // Save before moving
@@ -326,6 +1072,8 @@ mod tests {
             max_delay: Duration::from_millis(10000),
             backoff_multiplier: 2.0,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -352,6 +1100,8 @@ mod tests {
             max_delay: Duration::from_millis(1000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -367,4 +1117,586 @@ mod tests {
         let no_delay = policy.next_attempt_delay(&job_at_max, mock_error().as_ref());
         assert!(no_delay.is_none());
     }
+
+    #[test]
+    fn test_seeded_jitter_is_deterministic() {
+        let config = RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+
+        let policy_a = RetryPolicy::new_with_seed(config, 42).unwrap();
+        let policy_b = RetryPolicy::new_with_seed(config, 42).unwrap();
+
+        assert_eq!(policy_a.calculate_delay(2), policy_b.calculate_delay(2));
+    }
+
+    #[test]
+    fn test_compute_backoff_matches_calculate_delay() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        for attempt in 1..=4u32 {
+            assert_eq!(policy.compute_backoff(attempt), policy.calculate_delay(attempt));
+        }
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_stays_within_bounds() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(2000),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        let first = policy.calculate_delay_decorrelated(1, Duration::from_millis(0));
+        assert!(first >= config.base_delay && first <= config.max_delay);
+
+        let second = policy.calculate_delay_decorrelated(2, first);
+        assert!(second >= config.base_delay && second <= config.max_delay);
+    }
+
+    #[test]
+    fn test_should_retry_for_max_retries() {
+        let config = RetryConfig::default();
+        let policy = RetryPolicy::new(config).unwrap();
+
+        assert!(policy.should_retry_for(100, MaxRetries::Infinite));
+        assert!(policy.should_retry_for(2, MaxRetries::Count(3)));
+        assert!(!policy.should_retry_for(3, MaxRetries::Count(3)));
+    }
+
+    struct AlwaysPermanentClassifier;
+
+    impl RetryClassifier for AlwaysPermanentClassifier {
+        fn classify(&self, _err: &(dyn Error + Send + Sync)) -> ErrorKind {
+            ErrorKind::Permanent
+        }
+    }
+
+    #[test]
+    fn test_default_classifier_retries_everything_like_before() {
+        let config = RetryConfig::default();
+        let policy = RetryPolicy::new(config).unwrap();
+
+        assert!(policy.should_retry(1, mock_error().as_ref()));
+        assert!(!policy.should_retry(3, mock_error().as_ref()));
+    }
+
+    #[test]
+    fn test_permanent_classification_short_circuits_retry() {
+        let config = RetryConfig::default();
+        let policy =
+            RetryPolicy::new_with_classifier(config, Box::new(AlwaysPermanentClassifier))
+                .unwrap();
+
+        // Even on the very first attempt, a permanent failure should not retry
+        assert!(!policy.should_retry(1, mock_error().as_ref()));
+    }
+
+    struct ThrottlingClassifier {
+        retry_after: Option<Duration>,
+    }
+
+    impl RetryClassifier for ThrottlingClassifier {
+        fn classify(&self, _err: &(dyn Error + Send + Sync)) -> ErrorKind {
+            ErrorKind::Throttling {
+                retry_after: self.retry_after,
+            }
+        }
+    }
+
+    #[test]
+    fn test_throttling_retry_after_hint_overrides_computed_backoff() {
+        let config = RetryConfig {
+            max_delay: Duration::from_secs(60),
+            ..RetryConfig::default()
+        };
+        let policy = RetryPolicy::new_with_classifier(
+            config,
+            Box::new(ThrottlingClassifier {
+                retry_after: Some(Duration::from_secs(7)),
+            }),
+        )
+        .unwrap();
+        let job = create_test_queued_job(0);
+
+        let delay = policy
+            .next_attempt_delay(&job, mock_error().as_ref())
+            .unwrap();
+        assert_eq!(delay, Duration::from_secs(7));
+    }
+
+    #[test]
+    fn test_throttling_retry_after_hint_is_capped_at_max_delay() {
+        let config = RetryConfig {
+            max_delay: Duration::from_secs(10),
+            ..RetryConfig::default()
+        };
+        let policy = RetryPolicy::new_with_classifier(
+            config,
+            Box::new(ThrottlingClassifier {
+                retry_after: Some(Duration::from_secs(300)),
+            }),
+        )
+        .unwrap();
+        let job = create_test_queued_job(0);
+
+        let delay = policy
+            .next_attempt_delay(&job, mock_error().as_ref())
+            .unwrap();
+        assert_eq!(delay, Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_throttling_without_hint_falls_back_to_computed_backoff() {
+        let config = RetryConfig {
+            jitter: false,
+            ..RetryConfig::default()
+        };
+        let policy = RetryPolicy::new_with_classifier(
+            config,
+            Box::new(ThrottlingClassifier { retry_after: None }),
+        )
+        .unwrap();
+        let job = create_test_queued_job(0);
+
+        let delay = policy
+            .next_attempt_delay(&job, mock_error().as_ref())
+            .unwrap();
+        assert_eq!(delay, policy.calculate_delay(1));
+    }
+
+    #[test]
+    fn test_token_bucket_try_acquire_and_release() {
+        let bucket = RetryTokenBucket::new(10);
+        assert_eq!(bucket.available_tokens(), 10);
+
+        assert!(bucket.try_acquire(5));
+        assert_eq!(bucket.available_tokens(), 5);
+
+        assert!(!bucket.try_acquire(6));
+        assert_eq!(bucket.available_tokens(), 5);
+
+        bucket.release(100);
+        assert_eq!(bucket.available_tokens(), 10, "release should cap at capacity");
+    }
+
+    #[test]
+    fn test_token_bucket_default_starts_at_default_capacity() {
+        let bucket = RetryTokenBucket::default();
+        assert_eq!(bucket.available_tokens(), DEFAULT_TOKEN_BUCKET_CAPACITY);
+    }
+
+    #[test]
+    fn test_try_acquire_retry_and_record_success_aliases() {
+        let bucket = RetryTokenBucket::new(10);
+
+        assert!(bucket.try_acquire_retry(RETRY_COST_DEFAULT));
+        assert_eq!(bucket.available_tokens(), 5);
+
+        bucket.record_success();
+        assert_eq!(bucket.available_tokens(), 6);
+    }
+
+    #[test]
+    fn test_next_attempt_delay_drains_shared_token_bucket() {
+        let config = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let bucket = Arc::new(RetryTokenBucket::new(RETRY_COST_DEFAULT));
+        let policy = RetryPolicy::new_with_token_bucket(config, bucket.clone()).unwrap();
+        let job = create_test_queued_job(1);
+
+        // Bucket starts with exactly one retry's worth of tokens
+        assert!(policy
+            .next_attempt_delay(&job, mock_error().as_ref())
+            .is_some());
+        assert_eq!(bucket.available_tokens(), 0);
+
+        // Bucket is now empty, so the job is dropped rather than retried
+        assert!(policy
+            .next_attempt_delay(&job, mock_error().as_ref())
+            .is_none());
+    }
+
+    #[test]
+    fn test_decide_dead_letters_permanent_error() {
+        let config = RetryConfig::default();
+        let policy =
+            RetryPolicy::new_with_classifier(config, Box::new(AlwaysPermanentClassifier))
+                .unwrap();
+        let job = create_test_queued_job(0);
+
+        let decision = policy.decide(&job, mock_error().as_ref());
+        assert_eq!(
+            decision,
+            RetryDecision::DeadLetter {
+                reason: DeadLetterReason::PermanentError
+            }
+        );
+    }
+
+    #[test]
+    fn test_decide_dead_letters_once_max_attempts_reached() {
+        let config = RetryConfig {
+            max_attempts: 2,
+            ..RetryConfig::default()
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+        let job = create_test_queued_job(2);
+
+        let decision = policy.decide(&job, mock_error().as_ref());
+        assert_eq!(
+            decision,
+            RetryDecision::DeadLetter {
+                reason: DeadLetterReason::MaxAttemptsExceeded
+            }
+        );
+    }
+
+    #[test]
+    fn test_decide_drops_job_when_token_bucket_is_empty() {
+        let config = RetryConfig {
+            jitter: false,
+            ..RetryConfig::default()
+        };
+        let bucket = Arc::new(RetryTokenBucket::new(0));
+        let policy = RetryPolicy::new_with_token_bucket(config, bucket).unwrap();
+        let job = create_test_queued_job(0);
+
+        let decision = policy.decide(&job, mock_error().as_ref());
+        assert_eq!(decision, RetryDecision::Drop);
+    }
+
+    #[test]
+    fn test_decide_retries_with_computed_delay_by_default() {
+        let config = RetryConfig {
+            jitter: false,
+            ..RetryConfig::default()
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+        let job = create_test_queued_job(0);
+
+        let decision = policy.decide(&job, mock_error().as_ref());
+        assert_eq!(
+            decision,
+            RetryDecision::Retry {
+                delay: policy.calculate_delay(1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_success_refills_token_bucket() {
+        let config = RetryConfig::default();
+        let bucket = Arc::new(RetryTokenBucket::new(10));
+        bucket.try_acquire(10);
+        let policy = RetryPolicy::new_with_token_bucket(config, bucket.clone()).unwrap();
+
+        policy.record_success();
+        assert_eq!(bucket.available_tokens(), RETRY_SUCCESS_REFILL);
+    }
+
+    #[test]
+    fn test_jitter_strategy_from_bool_matches_old_defaults() {
+        assert_eq!(JitterStrategy::from(true), JitterStrategy::Full);
+        assert_eq!(JitterStrategy::from(false), JitterStrategy::None);
+    }
+
+    #[test]
+    fn test_none_strategy_returns_exact_delay() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy =
+            RetryPolicy::new_with_jitter_strategy(config, JitterStrategy::None).unwrap();
+
+        assert_eq!(policy.calculate_delay(2), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_equal_strategy_stays_within_bounds() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy =
+            RetryPolicy::new_with_jitter_strategy(config, JitterStrategy::Equal).unwrap();
+
+        for _ in 0..20 {
+            let delay = policy.calculate_delay(3);
+            assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+        }
+    }
+
+    #[test]
+    fn test_decorrelated_strategy_via_calculate_delay_stays_within_bounds() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(2000),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy =
+            RetryPolicy::new_with_jitter_strategy(config, JitterStrategy::Decorrelated).unwrap();
+
+        for attempt in 1..=5 {
+            let delay = policy.calculate_delay(attempt);
+            assert!(delay >= config.base_delay && delay <= config.max_delay);
+        }
+    }
+
+    #[test]
+    fn test_decorrelated_strategy_chains_from_previous_attempt_not_base_delay() {
+        // Seeded so the run is deterministic: assert the chain actually
+        // grows across attempts (i.e. attempt N's upper bound is driven by
+        // attempt N-1's *drawn* delay, not always `3 * base_delay`).
+        let config = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1_000_000),
+            backoff_multiplier: 2.0,
+            jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let mut policy = RetryPolicy::new_with_seed(config, 42).unwrap();
+        policy.jitter_strategy = JitterStrategy::Decorrelated;
+
+        let delay_at_5 = policy.calculate_delay(5);
+        let delay_at_9 = policy.calculate_delay(9);
+
+        // Both stay within the documented bounds...
+        assert!(delay_at_5 >= config.base_delay && delay_at_5 <= config.max_delay);
+        assert!(delay_at_9 >= config.base_delay && delay_at_9 <= config.max_delay);
+        // ...and later attempts are drawn from a wider, chain-grown range,
+        // so they're not capped to `3 * base_delay` the way a (buggy)
+        // always-reset-to-base-delay implementation would be.
+        assert!(delay_at_9 > Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_max_elapsed_below_base_delay_is_rejected() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(1000),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: Some(Duration::from_millis(500)),
+            backoff_kind: BackoffKind::Exponential,
+        };
+
+        assert!(RetryPolicy::new(config).is_err());
+    }
+
+    #[test]
+    fn test_next_attempt_delay_none_once_max_elapsed_exceeded() {
+        let config = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: Some(Duration::from_millis(500)),
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        let mut job = create_test_queued_job(1);
+        job.claimed_at = Utc::now() - chrono::Duration::milliseconds(450);
+
+        // Attempts remain (1 < 10), but the job has already been in flight
+        // for 450ms and the next delay (200ms) would push it past the
+        // 500ms max_elapsed budget, so it should route to DLQ.
+        assert!(policy
+            .next_attempt_delay(&job, mock_error().as_ref())
+            .is_none());
+    }
+
+    #[test]
+    fn test_next_attempt_delay_allowed_within_max_elapsed() {
+        let config = RetryConfig {
+            max_attempts: 10,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: Some(Duration::from_millis(5000)),
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        let job = create_test_queued_job(1);
+        assert_eq!(
+            policy.next_attempt_delay(&job, mock_error().as_ref()),
+            Some(Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn test_fixed_backoff_kind_returns_constant_delay() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Fixed,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        for attempt in 1..=5 {
+            assert_eq!(policy.calculate_delay(attempt), Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_linear_backoff_kind_scales_with_attempt() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Linear,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        assert_eq!(policy.calculate_delay(1), Duration::from_millis(100));
+        assert_eq!(policy.calculate_delay(2), Duration::from_millis(200));
+        assert_eq!(policy.calculate_delay(4), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn test_fibonacci_backoff_kind_grows_gentler_than_exponential() {
+        let config = RetryConfig {
+            max_attempts: 6,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(100_000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Fibonacci,
+        };
+        let policy = RetryPolicy::new(config).unwrap();
+
+        let expected = [100, 100, 200, 300, 500, 800];
+        for (i, expected_millis) in expected.iter().enumerate() {
+            assert_eq!(
+                policy.calculate_delay((i + 1) as u32),
+                Duration::from_millis(*expected_millis)
+            );
+        }
+    }
+
+    #[test]
+    fn test_backoff_kind_defaults_to_exponential() {
+        assert_eq!(RetryConfig::default().backoff_kind, BackoffKind::Exponential);
+    }
+
+    #[test]
+    fn test_exponential_backoff_strategy_doubles_and_caps() {
+        let strategy = ExponentialBackoff {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+            multiplier: 2.0,
+        };
+
+        assert_eq!(strategy.next_delay(1, None), Duration::from_millis(100));
+        assert_eq!(strategy.next_delay(2, None), Duration::from_millis(200));
+        assert_eq!(strategy.next_delay(4, None), Duration::from_millis(800));
+        assert_eq!(strategy.next_delay(10, None), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_fixed_backoff_strategy_ignores_attempt() {
+        let strategy = FixedBackoff {
+            delay: Duration::from_millis(250),
+        };
+
+        assert_eq!(strategy.next_delay(1, None), Duration::from_millis(250));
+        assert_eq!(
+            strategy.next_delay(9, Some(Duration::from_millis(999))),
+            Duration::from_millis(250)
+        );
+    }
+
+    #[test]
+    fn test_decorrelated_backoff_strategy_grows_from_previous_delay() {
+        let strategy = DecorrelatedBackoff {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(100_000),
+        };
+
+        assert_eq!(strategy.next_delay(1, None), Duration::from_millis(100));
+
+        let grown = strategy.next_delay(2, Some(Duration::from_millis(100)));
+        assert!(grown >= Duration::from_millis(100));
+        assert!(grown <= Duration::from_millis(300));
+
+        let capped = strategy.next_delay(3, Some(Duration::from_millis(100_000)));
+        assert!(capped <= Duration::from_millis(100_000));
+    }
+
+    #[test]
+    fn test_new_with_backoff_strategy_routes_calculate_delay_through_custom_strategy() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(5000),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
+        };
+        let strategy = Box::new(FixedBackoff {
+            delay: Duration::from_millis(42),
+        });
+        let policy = RetryPolicy::new_with_backoff_strategy(config, strategy).unwrap();
+
+        assert_eq!(policy.calculate_delay(1), Duration::from_millis(42));
+        assert_eq!(policy.calculate_delay(5), Duration::from_millis(42));
+    }
 }
@@ -0,0 +1,186 @@
+//! Long-poll / slow-job watchdog
+//!
+//! Times how long a dequeue poll or job execution takes and, as elapsed
+//! time crosses configurable thresholds, produces a structured
+//! [`ErrorContext`] at escalating severity — `Warning` first, then `Error`
+//! — without failing the job. This gives operators visibility into
+//! degradation building up before a job actually times out. A separate
+//! hard deadline still converts a genuinely stuck job into
+//! [`WorkerError::JobTimeout`].
+
+use crate::error::{ErrorCode, ErrorContext, ErrorSeverity, WorkerError};
+use std::time::{Duration, Instant};
+
+/// Escalation thresholds for a watched job
+#[derive(Debug, Clone, Copy)]
+pub struct WatchdogThresholds {
+    /// Elapsed time past which a `Warning`-severity event is emitted
+    pub warn_after: Duration,
+    /// Elapsed time past which an `Error`-severity event is emitted
+    pub error_after: Duration,
+    /// Elapsed time past which the job is considered stuck
+    pub hard_deadline: Duration,
+}
+
+impl Default for WatchdogThresholds {
+    fn default() -> Self {
+        Self {
+            warn_after: Duration::from_secs(10),
+            error_after: Duration::from_secs(30),
+            hard_deadline: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Ranks severities so escalation only fires on a genuine step up, not on
+/// every repeated check at the same level
+fn severity_rank(severity: ErrorSeverity) -> u8 {
+    match severity {
+        ErrorSeverity::Info => 0,
+        ErrorSeverity::Warning => 1,
+        ErrorSeverity::Error => 2,
+        ErrorSeverity::Critical => 3,
+    }
+}
+
+/// Tracks elapsed time for a single dequeue poll or job execution
+///
+/// Call [`JobTimer::check_progress`] periodically (e.g. once per polling
+/// loop iteration) to get a structured event the first time a new severity
+/// threshold is crossed, and [`JobTimer::check_hard_deadline`] to learn when
+/// the job should be failed outright.
+pub struct JobTimer {
+    name: String,
+    job_id: String,
+    started_at: Instant,
+    thresholds: WatchdogThresholds,
+    last_emitted: Option<ErrorSeverity>,
+}
+
+impl JobTimer {
+    /// Start a timer for `job_id` using the default thresholds
+    pub fn with_poll_timer(name: impl Into<String>, job_id: impl Into<String>) -> Self {
+        Self::with_thresholds(name, job_id, WatchdogThresholds::default())
+    }
+
+    /// Start a timer for `job_id` with custom escalation thresholds
+    pub fn with_thresholds(
+        name: impl Into<String>,
+        job_id: impl Into<String>,
+        thresholds: WatchdogThresholds,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            job_id: job_id.into(),
+            started_at: Instant::now(),
+            thresholds,
+            last_emitted: None,
+        }
+    }
+
+    /// Time elapsed since this timer was created
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
+
+    /// Compare elapsed time against the configured thresholds
+    ///
+    /// Returns `Some(ErrorContext)` the first time elapsed time crosses a
+    /// new, higher severity threshold than was last reported, and `None`
+    /// otherwise — so repeated calls from a polling loop don't re-log the
+    /// same warning every iteration.
+    pub fn check_progress(&mut self) -> Option<ErrorContext> {
+        let elapsed = self.elapsed();
+
+        let severity = if elapsed >= self.thresholds.error_after {
+            ErrorSeverity::Error
+        } else if elapsed >= self.thresholds.warn_after {
+            ErrorSeverity::Warning
+        } else {
+            return None;
+        };
+
+        let already_reported = self
+            .last_emitted
+            .is_some_and(|prev| severity_rank(severity) <= severity_rank(prev));
+        if already_reported {
+            return None;
+        }
+
+        self.last_emitted = Some(severity);
+
+        Some(
+            ErrorContext::new(ErrorCode::WorkerJobTimeout, severity)
+                .with_job_id(self.job_id.clone())
+                .with_metadata("poll_name", self.name.clone())
+                .with_metadata("elapsed_ms", elapsed.as_millis().to_string()),
+        )
+    }
+
+    /// Convert a genuinely stuck job into `WorkerError::JobTimeout` once the
+    /// hard deadline has passed
+    pub fn check_hard_deadline(&self) -> Option<WorkerError> {
+        if self.elapsed() >= self.thresholds.hard_deadline {
+            Some(WorkerError::JobTimeout {
+                job_id: self.job_id.clone(),
+                timeout: self.thresholds.hard_deadline,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instant_thresholds() -> WatchdogThresholds {
+        WatchdogThresholds {
+            warn_after: Duration::from_millis(0),
+            error_after: Duration::from_millis(20),
+            hard_deadline: Duration::from_millis(40),
+        }
+    }
+
+    #[test]
+    fn test_warns_once_then_escalates_to_error() {
+        let mut timer = JobTimer::with_thresholds("poll", "job-1", instant_thresholds());
+
+        let warning = timer.check_progress().expect("should warn immediately");
+        assert_eq!(warning.severity, ErrorSeverity::Warning);
+
+        // Same severity level again right away; should not re-emit
+        assert!(timer.check_progress().is_none());
+
+        std::thread::sleep(Duration::from_millis(25));
+        let error = timer
+            .check_progress()
+            .expect("should escalate to error after crossing error_after");
+        assert_eq!(error.severity, ErrorSeverity::Error);
+        assert_eq!(error.job_id, Some("job-1".to_string()));
+    }
+
+    #[test]
+    fn test_no_event_before_first_threshold() {
+        let thresholds = WatchdogThresholds {
+            warn_after: Duration::from_secs(60),
+            error_after: Duration::from_secs(120),
+            hard_deadline: Duration::from_secs(180),
+        };
+        let mut timer = JobTimer::with_thresholds("poll", "job-2", thresholds);
+        assert!(timer.check_progress().is_none());
+    }
+
+    #[test]
+    fn test_hard_deadline_converts_to_job_timeout() {
+        let timer = JobTimer::with_thresholds("poll", "job-3", instant_thresholds());
+        assert!(timer.check_hard_deadline().is_none());
+
+        std::thread::sleep(Duration::from_millis(45));
+        match timer.check_hard_deadline() {
+            Some(WorkerError::JobTimeout { job_id, .. }) => assert_eq!(job_id, "job-3"),
+            other => panic!("expected JobTimeout, got {:?}", other),
+        }
+    }
+}
@@ -22,7 +22,7 @@ fn test_queue_error_mapping() {
     let queue_error = QueueError::ConnectionFailed("Redis connection timeout".to_string());
 
     // Test error code generation
-    let error_code = queue_error.error_code();
+    let error_code = queue_error.error_code().to_string();
     assert_eq!(error_code, "QUEUE_CONNECTION_FAILED");
 
     // Test user-friendly message
@@ -45,7 +45,7 @@ fn test_worker_error_mapping() {
     };
 
     // Test error code generation
-    let error_code = worker_error.error_code();
+    let error_code = worker_error.error_code().to_string();
     assert_eq!(error_code, "WORKER_JOB_PROCESSING_FAILED");
 
     // Test user-friendly message
@@ -5,9 +5,9 @@
 
 #[cfg(test)]
 mod queue_trait_tests {
-    use chrono::Utc;
+    use chrono::{DateTime, Utc};
     use mimivibe_backend::queue::types::{JobMetadata, JobPayload, JobType};
-    use mimivibe_backend::queue::{JobStatus, Queue, QueuedJob};
+    use mimivibe_backend::queue::{DeadLetterEntry, JobStatus, Queue, QueuedJob};
     use std::error::Error;
     use uuid::Uuid;
 
@@ -40,6 +40,42 @@ mod queue_trait_tests {
         async fn get_queue_length(&self) -> Result<usize, Box<dyn Error>> {
             Ok(0)
         }
+
+        async fn move_to_dlq(
+            &self,
+            _job: &QueuedJob,
+            _reason: String,
+        ) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        async fn list_dlq(&self, _limit: usize) -> Result<Vec<DeadLetterEntry>, Box<dyn Error>> {
+            Ok(vec![])
+        }
+
+        async fn replay_dlq(&self, _job_id: &str) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        async fn enqueue_at(
+            &self,
+            _payload: JobPayload,
+            _when: DateTime<Utc>,
+        ) -> Result<String, Box<dyn Error>> {
+            Ok("mock-job-id".to_string())
+        }
+
+        async fn heartbeat(
+            &self,
+            _job_id: &str,
+            _consumer_id: &str,
+        ) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        async fn reclaim_expired(&self) -> Result<usize, Box<dyn Error>> {
+            Ok(0)
+        }
     }
 
     #[test]
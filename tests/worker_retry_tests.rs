@@ -13,7 +13,7 @@
 
 use chrono::Utc;
 use mimivibe_backend::queue::{JobPayload, Queue, QueuedJob};
-use mimivibe_backend::worker::retry::{RetryConfig, RetryPolicy};
+use mimivibe_backend::worker::retry::{BackoffKind, RetryConfig, RetryPolicy};
 use serde_json::json;
 use std::sync::Arc;
 use std::time::Duration;
@@ -67,6 +67,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(5000),
             backoff_multiplier: 2.0,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config);
@@ -80,6 +82,8 @@ mod retry_policy_tests {
                 max_delay: Duration::from_millis(5000),
                 backoff_multiplier: 2.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
             RetryConfig {
                 max_attempts: 3,
@@ -87,6 +91,8 @@ mod retry_policy_tests {
                 max_delay: Duration::from_millis(5000),
                 backoff_multiplier: 2.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
             RetryConfig {
                 max_attempts: 3,
@@ -94,6 +100,8 @@ mod retry_policy_tests {
                 max_delay: Duration::from_millis(50), // Invalid: max < base
                 backoff_multiplier: 2.0,
                 jitter: false,
+                max_elapsed: None,
+                backoff_kind: BackoffKind::Exponential,
             },
         ];
 
@@ -111,6 +119,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(5000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -132,6 +142,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(5000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -166,6 +178,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(3000), // Lower max to test capping
             backoff_multiplier: 3.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -196,6 +210,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(10000),
             backoff_multiplier: 2.0,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -235,6 +251,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(1000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -271,6 +289,8 @@ mod retry_policy_tests {
             max_delay: Duration::from_millis(1000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -317,6 +337,8 @@ mod worker_integration_tests {
             max_delay: Duration::from_millis(100),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
         let retry_policy = RetryPolicy::new(retry_config).unwrap();
 
@@ -375,6 +397,8 @@ mod worker_integration_tests {
             max_delay: Duration::from_millis(50),
             backoff_multiplier: 1.5,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let retry_policy = RetryPolicy::new(retry_config).unwrap();
@@ -426,6 +450,8 @@ mod worker_integration_tests {
             max_delay: Duration::from_millis(100),
             backoff_multiplier: 1.1,
             jitter: true,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -459,6 +485,8 @@ mod edge_case_tests {
             max_delay: Duration::from_millis(1000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy_result = RetryPolicy::new(config);
@@ -475,6 +503,8 @@ mod edge_case_tests {
             max_delay: Duration::from_millis(1000000), // Large max delay
             backoff_multiplier: 1000.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();
@@ -498,6 +528,8 @@ mod edge_case_tests {
             max_delay: Duration::from_millis(1000),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_elapsed: None,
+            backoff_kind: BackoffKind::Exponential,
         };
 
         let policy = RetryPolicy::new(config).unwrap();